@@ -112,6 +112,9 @@ fn bench_tokenize_with_offsets(c: &mut Criterion) {
     group.bench_function("baseline", |b| {
         b.iter(|| textprep::tokenize::tokenize_with_offsets(black_box(input)))
     });
+    group.bench_function("tokenize_iter", |b| {
+        b.iter(|| textprep::tokenize::tokenize_iter(black_box(input)).count())
+    });
     group.finish();
 }
 
@@ -1,8 +1,14 @@
 //! Fast keyword matching using Aho-Corasick.
 
-use aho_corasick::{AhoCorasick, MatchKind};
+use crate::offset::{OffsetCursor, OffsetUnit};
+use crate::tokenize::Tokenizer;
+use aho_corasick::{AhoCorasick, Anchored, Input, MatchKind, StartKind};
 use std::collections::HashMap;
 
+/// A keyword occurrence.
+///
+/// `start`/`end` are in the matcher's [`OffsetUnit`] (chars unless changed with
+/// [`FlashText::set_offset_unit`]).
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KeywordMatch {
@@ -17,6 +23,7 @@ pub struct FlashText {
     matcher: Option<AhoCorasick>,
     pattern_list: Vec<String>,
     case_insensitive: bool,
+    offset_unit: OffsetUnit,
 }
 
 impl FlashText {
@@ -26,9 +33,27 @@ impl FlashText {
             matcher: None,
             pattern_list: Vec::new(),
             case_insensitive: true,
+            offset_unit: OffsetUnit::Char,
         }
     }
 
+    /// Choose the unit for `KeywordMatch::start`/`end` (default: [`OffsetUnit::Char`]).
+    ///
+    /// [`OffsetUnit::Byte`] skips the char-counting pass entirely.
+    pub fn set_offset_unit(&mut self, unit: OffsetUnit) {
+        self.offset_unit = unit;
+    }
+
+    /// Builder-style variant of [`FlashText::set_offset_unit`].
+    pub fn with_offset_unit(mut self, unit: OffsetUnit) -> Self {
+        self.offset_unit = unit;
+        self
+    }
+
+    pub fn offset_unit(&self) -> OffsetUnit {
+        self.offset_unit
+    }
+
     pub fn add_keyword(&mut self, keyword: impl Into<String>, value: impl Into<String>) {
         let kw = keyword.into();
         let val = value.into();
@@ -41,6 +66,7 @@ impl FlashText {
         if self.matcher.is_none() {
             let ac = AhoCorasick::builder()
                 .match_kind(MatchKind::LeftmostLongest)
+                .start_kind(StartKind::Both)
                 .ascii_case_insensitive(self.case_insensitive)
                 .build(&self.pattern_list)
                 .expect("failed to build Aho-Corasick matcher");
@@ -56,39 +82,12 @@ impl FlashText {
         self.ensure_built();
         let matcher = self.matcher.as_ref().unwrap();
 
-        // `aho-corasick` yields byte offsets. Convert to char offsets in a single pass
+        // `aho-corasick` yields byte offsets. Convert to the requested unit in a single pass
         // by incrementally advancing from the last match boundary.
-        let mut last_byte = 0usize;
-        let mut last_char = 0usize;
+        let mut cursor = OffsetCursor::new(text, self.offset_unit);
 
         for mat in matcher.find_iter(text) {
-            let pattern = &self.pattern_list[mat.pattern()];
-            let value = self
-                .keywords
-                .get(pattern)
-                .cloned()
-                .unwrap_or_else(|| pattern.clone());
-
-            // Advance char counter from last match end → current match start.
-            if mat.start() >= last_byte {
-                last_char += text[last_byte..mat.start()].chars().count();
-            } else {
-                // Defensive: should not happen for `find_iter` (monotonic), but keep correctness.
-                last_char = text[..mat.start()].chars().count();
-            }
-            let start = last_char;
-            let len = text[mat.start()..mat.end()].chars().count();
-
-            out.push(KeywordMatch {
-                keyword: pattern.clone(),
-                value,
-                start,
-                end: start + len,
-            });
-
-            // Update last boundary to end of match.
-            last_byte = mat.end();
-            last_char = start + len;
+            out.push(self.keyword_match(mat.pattern().as_usize(), mat.range(), &mut cursor));
         }
     }
 
@@ -97,6 +96,90 @@ impl FlashText {
         self.find_into(text, &mut matches);
         matches
     }
+
+    /// Like [`FlashText::find_into`], but only keeps keywords that start and end on
+    /// token boundaries, as in the original FlashText algorithm: with a word
+    /// tokenizer, `"Apple"` is found in `"Apple pie"` but not in `"Pineapple"`.
+    ///
+    /// At each token start (outside a previous match) the longest keyword that ends at
+    /// a token end is taken: `"new"` is found in `"New Yorkers"` even if `"new york"` is
+    /// also a keyword.
+    pub fn find_tokens_into(
+        &mut self,
+        text: &str,
+        tokenizer: &dyn Tokenizer,
+        out: &mut Vec<KeywordMatch>,
+    ) {
+        out.clear();
+        self.ensure_built();
+        let matcher = self.matcher.as_ref().unwrap();
+
+        let mut tokens = Vec::new();
+        tokenizer.tokenize_into(text, &mut tokens);
+        let ends: Vec<usize> = tokens.iter().map(|t| t.byte_end).collect();
+        let mut cursor = OffsetCursor::new(text, self.offset_unit);
+        let mut next = 0;
+
+        for token in &tokens {
+            if token.byte_start < next {
+                continue;
+            }
+            // The longest keyword anchored here; if it ends inside a token, search again
+            // with the haystack cut at the previous token end.
+            let mut limit = text.len();
+            let mat = loop {
+                let input = Input::new(text)
+                    .range(token.byte_start..limit)
+                    .anchored(Anchored::Yes);
+                let Some(mat) = matcher.find(input) else {
+                    break None;
+                };
+                if ends.binary_search(&mat.end()).is_ok() {
+                    break Some(mat);
+                }
+                match ends.partition_point(|&e| e < mat.end()) {
+                    0 => break None,
+                    i => limit = ends[i - 1],
+                }
+                if limit <= token.byte_start {
+                    break None;
+                }
+            };
+            let Some(mat) = mat else {
+                continue;
+            };
+            next = mat.end();
+            out.push(self.keyword_match(mat.pattern().as_usize(), mat.range(), &mut cursor));
+        }
+    }
+
+    /// See [`FlashText::find_tokens_into`].
+    pub fn find_tokens(&mut self, text: &str, tokenizer: &dyn Tokenizer) -> Vec<KeywordMatch> {
+        let mut matches = Vec::new();
+        self.find_tokens_into(text, tokenizer, &mut matches);
+        matches
+    }
+
+    fn keyword_match(
+        &self,
+        pattern: usize,
+        range: std::ops::Range<usize>,
+        cursor: &mut OffsetCursor,
+    ) -> KeywordMatch {
+        let keyword = &self.pattern_list[pattern];
+        let value = self
+            .keywords
+            .get(keyword)
+            .cloned()
+            .unwrap_or_else(|| keyword.clone());
+
+        KeywordMatch {
+            keyword: keyword.clone(),
+            value,
+            start: cursor.advance_to(range.start),
+            end: cursor.advance_to(range.end),
+        }
+    }
 }
 
 impl Default for FlashText {
@@ -153,4 +236,43 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_find_byte_and_utf16_offsets() {
+        let text = "😀 Müller";
+        let mut ft = FlashText::new().with_offset_unit(OffsetUnit::Byte);
+        ft.add_keyword("Müller", "muller");
+        let m = &ft.find(text)[0];
+        assert_eq!(&text[m.start..m.end], "Müller");
+
+        ft.set_offset_unit(OffsetUnit::Utf16);
+        let m = &ft.find(text)[0];
+        assert_eq!((m.start, m.end), (3, 9));
+    }
+
+    #[test]
+    fn test_find_tokens_respects_token_boundaries() {
+        use crate::tokenize::WordTokenizer;
+
+        let mut ft = FlashText::new();
+        ft.add_keyword("apple", "fruit");
+        ft.add_keyword("new york", "nyc");
+        ft.add_keyword("new", "new");
+
+        let text = "Pineapple, apple; New Yorkers in New York";
+        assert_eq!(ft.find(text).len(), 4);
+        let found: Vec<(String, usize, usize)> = ft
+            .find_tokens(text, &WordTokenizer)
+            .into_iter()
+            .map(|m| (m.value, m.start, m.end))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("fruit".to_string(), 11, 16),
+                ("new".to_string(), 18, 21),
+                ("nyc".to_string(), 33, 41)
+            ]
+        );
+    }
 }
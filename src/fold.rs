@@ -1,13 +1,219 @@
 //! Case folding and diacritics stripping.
 
+pub mod arabic;
+pub mod chinese_ts;
+pub mod cyrillic;
+pub mod greek;
+pub mod hangul;
+pub mod hebrew;
+pub mod indic;
+mod letters;
+pub mod pinyin;
+pub mod vietnamese;
+
 use unicode_normalization::UnicodeNormalization;
+use unicode_properties::{GeneralCategory, UnicodeGeneralCategory};
+use unicode_script::{Script, UnicodeScript};
+
+/// Which scripts [`strip_diacritics_with`] removes combining marks from.
+///
+/// In Latin, Greek and Cyrillic, combining marks are accents: dropping them yields a
+/// usable (if lossy) search key. In many other scripts the "marks" are vowels, viramas or
+/// consonant modifiers, and stripping them produces nonsense (क्षि → कष). Those scripts
+/// are therefore opt-in.
+///
+/// A mark is attributed to its own script when it has one (e.g. Devanagari nukta), and
+/// otherwise to the script of the base character it attaches to (U+0301 after `e` is
+/// Latin, after `α` is Greek). Marks on script-neutral bases (digits, punctuation,
+/// symbols, whitespace) follow `latin_greek_cyrillic`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiacriticPolicy {
+    /// Latin, Greek, Cyrillic, and script-neutral bases.
+    pub latin_greek_cyrillic: bool,
+    /// Hebrew niqqud and cantillation marks.
+    pub hebrew: bool,
+    /// Arabic, Syriac and Thaana vowel marks (harakat) and other combining marks.
+    pub arabic: bool,
+    /// Indic abugidas: Devanagari, Bengali, Gurmukhi, Gujarati, Oriya, Tamil, Telugu,
+    /// Kannada, Malayalam, Sinhala (matras, virama, nukta, anusvara, …).
+    pub indic: bool,
+    /// Southeast Asian scripts: Thai, Lao, Khmer, Myanmar, Tai Tham, Balinese, Javanese, …
+    pub southeast_asian: bool,
+    /// Any other script with combining marks (Tibetan, Ethiopic, Mongolian, …).
+    pub other: bool,
+}
+
+impl DiacriticPolicy {
+    /// Strip every nonspacing/enclosing mark regardless of script.
+    pub fn all() -> Self {
+        Self {
+            latin_greek_cyrillic: true,
+            hebrew: true,
+            arabic: true,
+            indic: true,
+            southeast_asian: true,
+            other: true,
+        }
+    }
 
+    /// Strip nothing.
+    pub fn none() -> Self {
+        Self {
+            latin_greek_cyrillic: false,
+            hebrew: false,
+            arabic: false,
+            indic: false,
+            southeast_asian: false,
+            other: false,
+        }
+    }
+
+    fn strips(&self, group: ScriptGroup) -> bool {
+        match group {
+            ScriptGroup::Neutral | ScriptGroup::LatinGreekCyrillic => self.latin_greek_cyrillic,
+            ScriptGroup::Hebrew => self.hebrew,
+            ScriptGroup::Arabic => self.arabic,
+            ScriptGroup::Indic => self.indic,
+            ScriptGroup::SoutheastAsian => self.southeast_asian,
+            ScriptGroup::Other => self.other,
+        }
+    }
+}
+
+impl Default for DiacriticPolicy {
+    /// Only accents on Latin/Greek/Cyrillic (and script-neutral) bases.
+    fn default() -> Self {
+        Self {
+            latin_greek_cyrillic: true,
+            ..Self::none()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScriptGroup {
+    Neutral,
+    LatinGreekCyrillic,
+    Hebrew,
+    Arabic,
+    Indic,
+    SoutheastAsian,
+    Other,
+}
+
+fn script_group(c: char) -> ScriptGroup {
+    match c.script() {
+        Script::Common | Script::Inherited | Script::Unknown => ScriptGroup::Neutral,
+        Script::Latin | Script::Greek | Script::Cyrillic => ScriptGroup::LatinGreekCyrillic,
+        Script::Hebrew => ScriptGroup::Hebrew,
+        Script::Arabic | Script::Syriac | Script::Thaana => ScriptGroup::Arabic,
+        Script::Devanagari
+        | Script::Bengali
+        | Script::Gurmukhi
+        | Script::Gujarati
+        | Script::Oriya
+        | Script::Tamil
+        | Script::Telugu
+        | Script::Kannada
+        | Script::Malayalam
+        | Script::Sinhala => ScriptGroup::Indic,
+        Script::Thai
+        | Script::Lao
+        | Script::Khmer
+        | Script::Myanmar
+        | Script::Tai_Tham
+        | Script::Tai_Viet
+        | Script::New_Tai_Lue
+        | Script::Balinese
+        | Script::Javanese
+        | Script::Sundanese
+        | Script::Buginese => ScriptGroup::SoutheastAsian,
+        _ => ScriptGroup::Other,
+    }
+}
+
+/// Strip accents from Latin, Greek and Cyrillic text ("Müller" → "Muller").
+///
+/// Equivalent to [`strip_diacritics_with`] with [`DiacriticPolicy::default`]: marks that
+/// carry vowels or consonant modifiers in other scripts (Devanagari, Thai, Hebrew, Arabic,
+/// …) are kept. Use [`DiacriticPolicy::all`] to strip every Mn/Me mark.
 pub fn strip_diacritics(text: &str) -> String {
-    text.nfd().filter(|c| !is_combining_mark(*c)).collect()
+    strip_diacritics_with(text, &DiacriticPolicy::default())
+}
+
+/// Decompose (NFD), remove the combining marks selected by `policy`, and recompose (NFC).
+///
+/// Recomposition keeps untouched text intact: Hangul syllables and any marks that were
+/// not stripped come back in their composed form.
+pub fn strip_diacritics_with(text: &str, policy: &DiacriticPolicy) -> String {
+    if text.is_ascii() {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut base = ScriptGroup::Neutral;
+    for c in text.nfd() {
+        if is_combining_mark(c) {
+            let group = match script_group(c) {
+                ScriptGroup::Neutral => base,
+                own => own,
+            };
+            if policy.strips(group) {
+                continue;
+            }
+        } else {
+            base = script_group(c);
+        }
+        out.push(c);
+    }
+    out.nfc().collect()
+}
+
+/// Whether `c` is a nonspacing (Mn) or enclosing (Me) combining mark.
+///
+/// Spacing marks (Mc) are excluded: they are vowel signs in Indic scripts, not accents.
+pub fn is_combining_mark(c: char) -> bool {
+    if c.is_ascii() {
+        return false;
+    }
+    matches!(
+        c.general_category(),
+        GeneralCategory::NonspacingMark | GeneralCategory::EnclosingMark
+    )
+}
+
+/// Fold letters to their base letter: "Łódź" → "Lodz", "Æsir" → "AEsir", "ﬁ" → "fi".
+///
+/// This goes further than [`strip_diacritics`]: after removing accents, letters that
+/// have no decomposition (ø, ł, đ, ħ, ı, œ, æ, þ, ß, …) are mapped through a base-letter
+/// table. Latin folds to ASCII (like Lucene's `ASCIIFoldingFilter`); Greek and Cyrillic
+/// fold to the unaccented letter of the same script (ά → α, ё → е, ґ → г).
+///
+/// Case is preserved. Marks in other scripts are kept, as with [`strip_diacritics`].
+pub fn fold_letters(text: &str) -> String {
+    if text.is_ascii() {
+        return text.to_string();
+    }
+    let stripped = strip_diacritics(text);
+    let mut out = String::with_capacity(stripped.len());
+    for c in stripped.chars() {
+        match base_letter(c) {
+            Some(base) => out.push_str(base),
+            None => out.push(c),
+        }
+    }
+    out
 }
 
-fn is_combining_mark(c: char) -> bool {
-    matches!(c, '\u{0300}'..='\u{036F}' | '\u{1DC0}'..='\u{1DFF}' | '\u{20D0}'..='\u{20FF}' | '\u{FE20}'..='\u{FE2F}')
+/// Base-letter replacement for a single (already decomposed) char, if it has one.
+fn base_letter(c: char) -> Option<&'static str> {
+    if c.is_ascii() {
+        return None;
+    }
+    letters::BASE_LETTERS
+        .binary_search_by_key(&c, |&(k, _)| k)
+        .ok()
+        .map(|i| letters::BASE_LETTERS[i].1)
 }
 
 /// Lowercase using Rust's built-in Unicode-aware `to_lowercase`.
@@ -48,4 +254,63 @@ mod tests {
     fn test_strip_diacritics() {
         assert_eq!(strip_diacritics("Müller"), "Muller");
     }
+
+    #[test]
+    fn test_strip_diacritics_outside_legacy_ranges() {
+        // U+0483 COMBINING CYRILLIC TITLO and U+20DD COMBINING ENCLOSING CIRCLE (Me).
+        assert_eq!(strip_diacritics("a\u{0483}b\u{20DD}"), "ab");
+        // Spacing marks (Mc) are kept.
+        assert_eq!(
+            strip_diacritics_with("\u{0915}\u{093E}", &DiacriticPolicy::all()),
+            "\u{0915}\u{093E}"
+        );
+    }
+
+    #[test]
+    fn test_strip_diacritics_preserves_non_accent_scripts_by_default() {
+        // Devanagari virama + vowel sign I (Mn), Thai sara i, Hebrew patah, Hangul.
+        for s in ["क्षि", "กิน", "בַּיִת", "한국어"] {
+            assert_eq!(strip_diacritics(s), s);
+        }
+        // Mixed: only the Latin accent goes.
+        assert_eq!(strip_diacritics("café क्षि"), "cafe क्षि");
+    }
+
+    #[test]
+    fn test_strip_diacritics_with_per_script_options() {
+        let hebrew = DiacriticPolicy {
+            hebrew: true,
+            ..DiacriticPolicy::default()
+        };
+        assert_eq!(strip_diacritics_with("שָׁלוֹם é", &hebrew), "שלום e");
+
+        let indic = DiacriticPolicy {
+            indic: true,
+            ..DiacriticPolicy::none()
+        };
+        assert_eq!(strip_diacritics_with("हिंदी é", &indic), "हिदी é");
+
+        let arabic = DiacriticPolicy {
+            arabic: true,
+            ..DiacriticPolicy::none()
+        };
+        assert_eq!(strip_diacritics_with("مُحَمَّد", &arabic), "محمد");
+    }
+
+    #[test]
+    fn test_fold_letters() {
+        assert_eq!(fold_letters("Łódź"), "Lodz");
+        assert_eq!(fold_letters("Łódź"), fold_letters("Lodz"));
+        assert_eq!(fold_letters("ø ł đ ħ ı œ æ þ ß"), "o l d h i oe ae th ss");
+        assert_eq!(fold_letters("Ærøskøbing"), "AEroskobing");
+        assert_eq!(fold_letters("ﬁnance"), "finance");
+        assert_eq!(fold_letters("Ἀθῆναι"), "Αθηναι");
+        assert_eq!(fold_letters("ёлка ґанок"), "елка ганок");
+        assert_eq!(fold_letters("한국 क्षि"), "한국 क्षि");
+    }
+
+    #[test]
+    fn test_base_letters_table_is_sorted() {
+        assert!(letters::BASE_LETTERS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
 }
@@ -0,0 +1,161 @@
+//! Arabic orthographic normalization for search.
+//!
+//! Arabic text is written with a lot of optional or variant orthography: hamza may or
+//! may not be written on alef, final yeh is often typed as alef maksura, teh marbuta as
+//! heh, and short vowels (harakat) are usually omitted. Keys built from raw text
+//! therefore miss obvious matches.
+//!
+//! [`ArabicConfig::lucene`] reproduces Lucene's `ArabicNormalizer`; the default config
+//! additionally folds alef wasla and hamza carried on waw/yeh.
+
+use unicode_normalization::UnicodeNormalization;
+
+const ALEF: char = '\u{0627}';
+const ALEF_MADDA: char = '\u{0622}';
+const ALEF_HAMZA_ABOVE: char = '\u{0623}';
+const ALEF_HAMZA_BELOW: char = '\u{0625}';
+const ALEF_WASLA: char = '\u{0671}';
+const WAW: char = '\u{0648}';
+const WAW_HAMZA: char = '\u{0624}';
+const YEH: char = '\u{064A}';
+const YEH_HAMZA: char = '\u{0626}';
+const DOTLESS_YEH: char = '\u{0649}'; // alef maksura
+const TEH_MARBUTA: char = '\u{0629}';
+const HEH: char = '\u{0647}';
+const TATWEEL: char = '\u{0640}';
+const SUPERSCRIPT_ALEF: char = '\u{0670}';
+
+/// Which Arabic normalization rules to apply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArabicConfig {
+    /// أ إ آ → ا.
+    pub alef: bool,
+    /// ٱ (alef wasla) → ا. Only applies when `alef` is also set.
+    pub alef_wasla: bool,
+    /// ى (alef maksura / dotless yeh) → ي.
+    pub yeh: bool,
+    /// ة (teh marbuta) → ه.
+    pub teh_marbuta: bool,
+    /// ؤ → و, ئ → ي (hamza on a carrier is dropped).
+    pub hamza_carrier: bool,
+    /// Remove tatweel/kashida (U+0640), which is purely typographic elongation.
+    pub tatweel: bool,
+    /// Remove harakat (U+064B..U+0652).
+    pub harakat: bool,
+    /// Remove superscript (dagger) alef (U+0670), which Lucene keeps.
+    pub superscript_alef: bool,
+}
+
+impl ArabicConfig {
+    /// The rule set of Lucene's `ArabicNormalizer`.
+    pub fn lucene() -> Self {
+        Self {
+            alef: true,
+            alef_wasla: false,
+            yeh: true,
+            teh_marbuta: true,
+            hamza_carrier: false,
+            tatweel: true,
+            harakat: true,
+            superscript_alef: false,
+        }
+    }
+}
+
+impl Default for ArabicConfig {
+    /// All rules enabled.
+    fn default() -> Self {
+        Self {
+            alef: true,
+            alef_wasla: true,
+            yeh: true,
+            teh_marbuta: true,
+            hamza_carrier: true,
+            tatweel: true,
+            harakat: true,
+            superscript_alef: true,
+        }
+    }
+}
+
+fn is_harakah(c: char) -> bool {
+    matches!(c, '\u{064B}'..='\u{0652}')
+}
+
+/// Normalize Arabic text with [`ArabicConfig::default`].
+pub fn normalize(text: &str) -> String {
+    normalize_with(text, &ArabicConfig::default())
+}
+
+/// Normalize Arabic text with an explicit rule set.
+///
+/// Input is composed (NFC) first so that decomposed hamza/madda sequences
+/// (e.g. `ا` + U+0654) are treated like their precomposed letters, so the output is
+/// NFC even where no rule applies; text that is already NFC and has no Arabic is
+/// returned unchanged.
+pub fn normalize_with(text: &str, cfg: &ArabicConfig) -> String {
+    let mut out = String::with_capacity(text.len());
+    normalize_into(text, cfg, &mut out);
+    out
+}
+
+/// Like [`normalize_with`], but writes into an existing `String`.
+pub fn normalize_into(text: &str, cfg: &ArabicConfig, out: &mut String) {
+    out.clear();
+    out.reserve(text.len());
+    for c in text.nfc() {
+        let mapped = match c {
+            ALEF_MADDA | ALEF_HAMZA_ABOVE | ALEF_HAMZA_BELOW if cfg.alef => ALEF,
+            ALEF_WASLA if cfg.alef && cfg.alef_wasla => ALEF,
+            DOTLESS_YEH if cfg.yeh => YEH,
+            TEH_MARBUTA if cfg.teh_marbuta => HEH,
+            WAW_HAMZA if cfg.hamza_carrier => WAW,
+            YEH_HAMZA if cfg.hamza_carrier => YEH,
+            TATWEEL if cfg.tatweel => continue,
+            c if cfg.harakat && is_harakah(c) => continue,
+            SUPERSCRIPT_ALEF if cfg.superscript_alef => continue,
+            c => c,
+        };
+        out.push(mapped);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lucene_rules() {
+        let cfg = ArabicConfig::lucene();
+        assert_eq!(normalize_with("أحمد إسلام آمن", &cfg), "احمد اسلام امن");
+        assert_eq!(normalize_with("مكتبة", &cfg), "مكتبه");
+        assert_eq!(normalize_with("على", &cfg), "علي");
+        assert_eq!(normalize_with("جميـــل", &cfg), "جميل");
+        assert_eq!(normalize_with("مُحَمَّدٌ", &cfg), "محمد");
+        // Lucene leaves alef wasla and hamza carriers alone.
+        assert_eq!(normalize_with("ٱلله مسؤول", &cfg), "ٱلله مسؤول");
+        assert_eq!(normalize_with("هٰذا", &cfg), "هٰذا");
+        assert_eq!(normalize("هٰذا"), "هذا");
+    }
+
+    #[test]
+    fn test_default_folds_wasla_and_hamza_carriers() {
+        assert_eq!(normalize("ٱلله"), "الله");
+        assert_eq!(normalize("مسؤول قائمة"), "مسوول قايمه");
+    }
+
+    #[test]
+    fn test_decomposed_hamza_is_composed_first() {
+        assert_eq!(normalize("\u{0627}\u{0654}حمد"), "احمد");
+    }
+
+    #[test]
+    fn test_harakat_optional() {
+        let cfg = ArabicConfig {
+            harakat: false,
+            ..ArabicConfig::default()
+        };
+        assert_eq!(normalize_with("كَتَبَ", &cfg), "كَتَبَ");
+    }
+}
@@ -0,0 +1,222 @@
+//! Traditional ↔ Simplified Chinese conversion.
+//!
+//! Conversion is table-driven, in the format used by OpenCC
+//! (`STCharacters.txt`, `STPhrases.txt`, `TSCharacters.txt`, `TSPhrases.txt`): one entry
+//! per line, `source<TAB>candidate [candidate…]`, where the first candidate is used.
+//!
+//! Characters are converted one by one, except where a phrase entry matches: phrases are
+//! found with leftmost-longest matching, so 头发 → 頭髮 (not 頭發) and 乾隆 stays 乾隆
+//! in T→S even though 乾 → 干 on its own.
+//!
+//! The bundled tables were compiled for this crate (only the format is OpenCC's). They
+//! cover the common one-to-one character mappings plus the phrases needed for the
+//! frequent one-to-many simplifications (发/發/髮, 干/幹/乾, 后/後, 里/裏, 面/麵,
+//! 台/臺/颱, 复/復/複, …). For full coverage, load the upstream OpenCC
+//! dictionaries with [`ChineseConverter::from_opencc`].
+//!
+//! For building *keys*, conversion only needs to be consistent, not perfect: folding both
+//! the index and the query to one script (usually Simplified, which is many-to-one)
+//! makes 臺灣 and 台湾 the same key.
+
+use aho_corasick::{AhoCorasick, MatchKind};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+static ST_CHARACTERS: &str = include_str!("data/st_characters.txt");
+static ST_PHRASES: &str = include_str!("data/st_phrases.txt");
+static TS_CHARACTERS: &str = include_str!("data/ts_characters.txt");
+static TS_PHRASES: &str = include_str!("data/ts_phrases.txt");
+
+/// Target script when folding Chinese text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChineseScript {
+    /// Convert Traditional characters to Simplified.
+    Simplified,
+    /// Convert Simplified characters to Traditional.
+    Traditional,
+}
+
+/// A character + phrase conversion table.
+#[derive(Debug, Clone)]
+pub struct ChineseConverter {
+    chars: HashMap<char, char>,
+    phrase_values: Vec<String>,
+    matcher: Option<AhoCorasick>,
+}
+
+impl ChineseConverter {
+    /// Build a converter from explicit character and phrase mappings.
+    pub fn new<C, P, S, T>(chars: C, phrases: P) -> Self
+    where
+        C: IntoIterator<Item = (char, char)>,
+        P: IntoIterator<Item = (S, T)>,
+        S: Into<String>,
+        T: Into<String>,
+    {
+        let chars = chars.into_iter().collect();
+        let mut phrase_keys = Vec::new();
+        let mut phrase_values = Vec::new();
+        for (k, v) in phrases {
+            let k = k.into();
+            if k.is_empty() {
+                continue;
+            }
+            phrase_keys.push(k);
+            phrase_values.push(v.into());
+        }
+        let matcher = if phrase_keys.is_empty() {
+            None
+        } else {
+            Some(
+                AhoCorasick::builder()
+                    .match_kind(MatchKind::LeftmostLongest)
+                    .build(&phrase_keys)
+                    .expect("failed to build Aho-Corasick matcher"),
+            )
+        };
+        Self {
+            chars,
+            phrase_values,
+            matcher,
+        }
+    }
+
+    /// Build a converter from OpenCC dictionary text (character table + phrase table).
+    ///
+    /// Character-table entries whose key or first candidate is longer than one char are
+    /// treated as phrases. Blank lines and lines starting with `#` are ignored.
+    pub fn from_opencc(characters: &str, phrases: &str) -> Self {
+        let mut char_map = Vec::new();
+        let mut phrase_map = Vec::new();
+        for (k, v) in parse_opencc(characters).chain(parse_opencc(phrases)) {
+            let mut kc = k.chars();
+            let mut vc = v.chars();
+            match (kc.next(), kc.next(), vc.next(), vc.next()) {
+                (Some(k1), None, Some(v1), None) => char_map.push((k1, v1)),
+                _ => phrase_map.push((k, v)),
+            }
+        }
+        Self::new(char_map, phrase_map)
+    }
+
+    /// Convert `text`.
+    pub fn convert(&self, text: &str) -> String {
+        let mut out = String::with_capacity(text.len());
+        self.convert_into(text, &mut out);
+        out
+    }
+
+    /// Like [`ChineseConverter::convert`], but writes into an existing `String`.
+    pub fn convert_into(&self, text: &str, out: &mut String) {
+        out.clear();
+        out.reserve(text.len());
+        let mut last = 0usize;
+        if let Some(matcher) = &self.matcher {
+            for mat in matcher.find_iter(text) {
+                self.convert_chars(&text[last..mat.start()], out);
+                out.push_str(&self.phrase_values[mat.pattern()]);
+                last = mat.end();
+            }
+        }
+        self.convert_chars(&text[last..], out);
+    }
+
+    /// Convert a single character with the character table only (phrases are ignored).
+    pub(crate) fn convert_char(&self, c: char) -> char {
+        self.chars.get(&c).copied().unwrap_or(c)
+    }
+
+    fn convert_chars(&self, text: &str, out: &mut String) {
+        for c in text.chars() {
+            out.push(self.convert_char(c));
+        }
+    }
+}
+
+/// Iterate `(source, first candidate)` pairs of an OpenCC dictionary.
+fn parse_opencc(dict: &str) -> impl Iterator<Item = (&str, &str)> {
+    dict.lines().filter_map(|line| {
+        let line = line.trim_end_matches('\r');
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+        let (k, vs) = line.split_once('\t')?;
+        let v = vs.split(' ').next()?;
+        (!k.is_empty() && !v.is_empty()).then_some((k, v))
+    })
+}
+
+/// Bundled Traditional → Simplified converter.
+pub fn traditional_to_simplified() -> &'static ChineseConverter {
+    static CONVERTER: OnceLock<ChineseConverter> = OnceLock::new();
+    CONVERTER.get_or_init(|| ChineseConverter::from_opencc(TS_CHARACTERS, TS_PHRASES))
+}
+
+/// Bundled Simplified → Traditional converter.
+pub fn simplified_to_traditional() -> &'static ChineseConverter {
+    static CONVERTER: OnceLock<ChineseConverter> = OnceLock::new();
+    CONVERTER.get_or_init(|| ChineseConverter::from_opencc(ST_CHARACTERS, ST_PHRASES))
+}
+
+/// Convert to Simplified Chinese with the bundled tables.
+pub fn to_simplified(text: &str) -> String {
+    traditional_to_simplified().convert(text)
+}
+
+/// Convert to Traditional Chinese with the bundled tables.
+pub fn to_traditional(text: &str) -> String {
+    simplified_to_traditional().convert(text)
+}
+
+/// Convert to `script` with the bundled tables, writing into an existing `String`.
+pub fn convert_into(text: &str, script: ChineseScript, out: &mut String) {
+    match script {
+        ChineseScript::Simplified => traditional_to_simplified().convert_into(text, out),
+        ChineseScript::Traditional => simplified_to_traditional().convert_into(text, out),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_simplified() {
+        assert_eq!(to_simplified("臺灣"), "台湾");
+        assert_eq!(to_simplified("頭髮 發展 後面"), "头发 发展 后面");
+        assert_eq!(to_simplified("乾隆 乾淨"), "乾隆 干净");
+        assert_eq!(to_simplified("Hello, 東京!"), "Hello, 东京!");
+    }
+
+    #[test]
+    fn test_to_traditional_uses_phrases() {
+        assert_eq!(to_traditional("头发"), "頭髮");
+        assert_eq!(to_traditional("发展"), "發展");
+        assert_eq!(to_traditional("干净 干部"), "乾淨 幹部");
+        assert_eq!(to_traditional("皇后 后面"), "皇后 後面");
+        assert_eq!(to_traditional("方便面"), "方便麵");
+    }
+
+    #[test]
+    fn test_roundtrip_keys_agree() {
+        for (t, s) in [("臺灣", "台湾"), ("頭髮", "头发"), ("鬆開", "松开")] {
+            assert_eq!(to_simplified(t), to_simplified(s));
+        }
+    }
+
+    #[test]
+    fn test_from_opencc_longest_match() {
+        let conv = ChineseConverter::from_opencc("a\tb\n", "# comment\naa\tX\naaa\tY Z\n");
+        assert_eq!(conv.convert("aaaa a"), "Yb b");
+    }
+
+    #[test]
+    fn test_bundled_tables_parse() {
+        for dict in [ST_CHARACTERS, ST_PHRASES, TS_CHARACTERS, TS_PHRASES] {
+            for line in dict.lines().filter(|l| !l.starts_with('#')) {
+                assert!(line.contains('\t'), "malformed entry: {line:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! Cyrillic folding for search.
+//!
+//! Two spelling variants routinely break matches in Cyrillic text:
+//!
+//! - Russian ё is optional in everyday writing (ёлка / елка, Королёв / Королев);
+//! - Ukrainian and Belarusian use an apostrophe as a letter (п'ять, сем'я, аб'ява), and it
+//!   is typed as `'`, `’`, `ʼ` or worse depending on the keyboard and editor.
+//!
+//! [`super::strip_diacritics`] does fold ё to е, but it also folds й → и and ў → у, which
+//! are different letters; use these rules with `strip_diacritics: false` (or with a
+//! [`super::DiacriticPolicy`] that excludes Cyrillic) to avoid that.
+
+use unicode_normalization::UnicodeNormalization;
+use unicode_script::{Script, UnicodeScript};
+
+/// Which Cyrillic folding rules to apply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CyrillicConfig {
+    /// ё → е, Ё → Е.
+    pub yo: bool,
+    /// Map apostrophe look-alikes between two Cyrillic letters (`’ ʼ ‘ ` ´ ′ ＇`) to an
+    /// ASCII `'`. Quotes elsewhere are left alone.
+    pub apostrophes: bool,
+}
+
+impl Default for CyrillicConfig {
+    /// All rules enabled.
+    fn default() -> Self {
+        Self {
+            yo: true,
+            apostrophes: true,
+        }
+    }
+}
+
+fn is_apostrophe_like(c: char) -> bool {
+    matches!(
+        c,
+        '\'' | '\u{2019}' | '\u{02BC}' | '\u{2018}' | '`' | '\u{00B4}' | '\u{2032}' | '\u{FF07}'
+    )
+}
+
+fn is_cyrillic_letter(c: char) -> bool {
+    c.is_alphabetic() && c.script() == Script::Cyrillic
+}
+
+/// Normalize Cyrillic text with [`CyrillicConfig::default`].
+pub fn normalize(text: &str) -> String {
+    normalize_with(text, &CyrillicConfig::default())
+}
+
+/// Normalize Cyrillic text with an explicit rule set. The result is NFC.
+pub fn normalize_with(text: &str, cfg: &CyrillicConfig) -> String {
+    let mut out = String::with_capacity(text.len());
+    normalize_into(text, cfg, &mut out);
+    out
+}
+
+/// Like [`normalize_with`], but writes into an existing `String`.
+pub fn normalize_into(text: &str, cfg: &CyrillicConfig, out: &mut String) {
+    out.clear();
+    out.reserve(text.len());
+    let mut chars = text.nfc().peekable();
+    let mut prev = None;
+    while let Some(c) = chars.next() {
+        let mapped = match c {
+            'ё' if cfg.yo => 'е',
+            'Ё' if cfg.yo => 'Е',
+            c if cfg.apostrophes
+                && is_apostrophe_like(c)
+                && prev.is_some_and(is_cyrillic_letter)
+                && chars.peek().is_some_and(|&n| is_cyrillic_letter(n)) =>
+            {
+                '\''
+            }
+            c => c,
+        };
+        out.push(mapped);
+        prev = Some(c);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_yo() {
+        assert_eq!(normalize("Королёв, ёлка, ЁЖ"), "Королев, елка, ЕЖ");
+        // Decomposed ё.
+        assert_eq!(normalize("е\u{0308}лка"), "елка");
+        // й is a separate letter and is kept.
+        assert_eq!(normalize("йод"), "йод");
+    }
+
+    #[test]
+    fn test_apostrophes() {
+        assert_eq!(normalize("п’ять сімʼя аб‘ява"), "п'ять сім'я аб'ява");
+        // Quotes around words are not apostrophes.
+        assert_eq!(normalize("‘слово’"), "‘слово’");
+        // Latin text is untouched.
+        assert_eq!(normalize("don’t"), "don’t");
+    }
+
+    #[test]
+    fn test_toggles() {
+        let cfg = CyrillicConfig {
+            yo: false,
+            ..CyrillicConfig::default()
+        };
+        assert_eq!(normalize_with("ёлка п’ять", &cfg), "ёлка п'ять");
+    }
+}
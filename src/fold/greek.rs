@@ -0,0 +1,196 @@
+//! Greek folding for search.
+//!
+//! [`super::strip_diacritics`] removes Greek accents along with every other Latin, Greek
+//! and Cyrillic mark. These rules are narrower: they only touch marks on Greek letters,
+//! so they can be combined with `strip_diacritics: false` when other scripts in the same
+//! text must keep their accents. They also handle two Greek-specific mismatches:
+//!
+//! - final sigma: lowercasing `ΛΟΓΟΣ` gives `λογος` (with `ς`), while the same letter
+//!   mid-word is `σ`; prefix and substring matching need a single form;
+//! - all-caps text is conventionally written without accents (`ΑΘΗΝΑ` for `Αθήνα`), and
+//!   a dialytika appears instead where the accent kept two vowels apart (`Μάιος` →
+//!   `ΜΑΪΟΣ`). [`to_upper`] produces that form.
+
+use unicode_normalization::UnicodeNormalization;
+use unicode_script::{Script, UnicodeScript};
+
+const OXIA: char = '\u{0301}'; // also tonos after NFD
+const VARIA: char = '\u{0300}';
+const PERISPOMENI: char = '\u{0342}';
+const PSILI: char = '\u{0313}';
+const DASIA: char = '\u{0314}';
+const YPOGEGRAMMENI: char = '\u{0345}';
+const DIALYTIKA: char = '\u{0308}';
+
+/// Which Greek folding rules to apply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GreekConfig {
+    /// Remove tonos and the polytonic accents and breathings (oxia, varia, perispomeni,
+    /// psili, dasia). Iota subscript is kept.
+    pub accents: bool,
+    /// Remove dialytika (ϊ → ι, ϋ → υ).
+    pub dialytika: bool,
+    /// Fold final sigma to medial sigma (ς → σ).
+    pub final_sigma: bool,
+}
+
+impl Default for GreekConfig {
+    /// All rules enabled.
+    fn default() -> Self {
+        Self {
+            accents: true,
+            dialytika: true,
+            final_sigma: true,
+        }
+    }
+}
+
+fn is_greek(c: char) -> bool {
+    c.script() == Script::Greek
+}
+
+fn is_accent(c: char) -> bool {
+    matches!(c, OXIA | VARIA | PERISPOMENI | PSILI | DASIA)
+}
+
+/// Normalize Greek text with [`GreekConfig::default`].
+pub fn normalize(text: &str) -> String {
+    normalize_with(text, &GreekConfig::default())
+}
+
+/// Normalize Greek text with an explicit rule set.
+///
+/// Marks are only removed from Greek base letters; the result is NFC.
+pub fn normalize_with(text: &str, cfg: &GreekConfig) -> String {
+    let mut out = String::with_capacity(text.len());
+    normalize_into(text, cfg, &mut out);
+    out
+}
+
+/// Like [`normalize_with`], but writes into an existing `String`.
+pub fn normalize_into(text: &str, cfg: &GreekConfig, out: &mut String) {
+    out.clear();
+    out.reserve(text.len());
+    let mut base_is_greek = false;
+    let filtered = text.nfd().filter_map(|c| {
+        if super::is_combining_mark(c) {
+            let drop = base_is_greek
+                && ((cfg.accents && is_accent(c)) || (cfg.dialytika && c == DIALYTIKA));
+            return (!drop).then_some(c);
+        }
+        base_is_greek = is_greek(c);
+        Some(match c {
+            'ς' if cfg.final_sigma => 'σ',
+            c => c,
+        })
+    });
+    out.extend(filtered.nfc());
+}
+
+/// Uppercase Greek the way it is typeset in all caps: without accents, with a dialytika
+/// where the removed accent separated two vowels.
+///
+/// `"Αθήνα"` → `"ΑΘΗΝΑ"`, `"Μάιος"` → `"ΜΑΪΟΣ"`, `"ταΐζω"` → `"ΤΑΪΖΩ"`. Other scripts are
+/// uppercased normally (`str::to_uppercase`).
+pub fn to_upper(text: &str) -> String {
+    let chars: Vec<char> = text.nfd().collect();
+    let mut decomposed = String::with_capacity(text.len());
+    let mut base: Option<char> = None;
+    let mut dropped_accent = false;
+    let mut needs_dialytika = false;
+    for (i, &c) in chars.iter().enumerate() {
+        if super::is_combining_mark(c) {
+            if base.is_some_and(is_greek) && is_accent(c) {
+                dropped_accent = true;
+                continue;
+            }
+            if c == YPOGEGRAMMENI {
+                // Uppercases to a full iota (ᾳ → ΑΙ).
+                decomposed.extend(c.to_uppercase());
+                continue;
+            }
+            decomposed.push(c);
+            continue;
+        }
+
+        if needs_dialytika {
+            decomposed.push(DIALYTIKA);
+        }
+        needs_dialytika = false;
+
+        // An accent on the first vowel of what would otherwise read as a diphthong
+        // (άι, όι, έυ, …) turns into a dialytika on the second vowel.
+        let prev = base.filter(|_| dropped_accent);
+        let next_is_bare = chars
+            .get(i + 1)
+            .map_or(true, |&n| !super::is_combining_mark(n));
+        if let Some(p) = prev {
+            if forms_diphthong(p, c) && next_is_bare {
+                needs_dialytika = true;
+            }
+        }
+        base = Some(c);
+        dropped_accent = false;
+        decomposed.extend(c.to_uppercase());
+    }
+    if needs_dialytika {
+        decomposed.push(DIALYTIKA);
+    }
+    decomposed.nfc().collect()
+}
+
+fn forms_diphthong(first: char, second: char) -> bool {
+    let lower = |c: char| c.to_lowercase().next().unwrap_or(c);
+    matches!(
+        (lower(first), lower(second)),
+        ('α' | 'ε' | 'ο' | 'υ', 'ι') | ('α' | 'ε' | 'η' | 'ο', 'υ')
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accents_and_final_sigma() {
+        assert_eq!(normalize("Αθήνα"), "Αθηνα");
+        assert_eq!(normalize("λόγος"), "λογοσ");
+        assert_eq!(normalize("προϊόν"), "προιον");
+        // Polytonic.
+        assert_eq!(normalize("ἐν ἀρχῇ ἦν ὁ λόγος"), "εν αρχῃ ην ο λογοσ");
+    }
+
+    #[test]
+    fn test_only_greek_bases_are_touched() {
+        assert_eq!(normalize("café Αθήνα naïve"), "café Αθηνα naïve");
+    }
+
+    #[test]
+    fn test_toggles() {
+        let cfg = GreekConfig {
+            dialytika: false,
+            final_sigma: false,
+            ..GreekConfig::default()
+        };
+        assert_eq!(normalize_with("προϊόντος", &cfg), "προϊοντος");
+    }
+
+    #[test]
+    fn test_lowercased_caps_match_accented_text() {
+        let key = |s: &str| normalize(&s.to_lowercase());
+        assert_eq!(key("ΑΘΗΝΑ"), key("Αθήνα"));
+        assert_eq!(key("ΛΟΓΟΣ"), key("λόγος"));
+    }
+
+    #[test]
+    fn test_to_upper() {
+        assert_eq!(to_upper("Αθήνα"), "ΑΘΗΝΑ");
+        assert_eq!(to_upper("καφές"), "ΚΑΦΕΣ");
+        assert_eq!(to_upper("Μάιος"), "ΜΑΪΟΣ");
+        assert_eq!(to_upper("ταΐζω"), "ΤΑΪΖΩ");
+        assert_eq!(to_upper("ρολόι"), "ΡΟΛΟΪ");
+        assert_eq!(to_upper("είναι"), "ΕΙΝΑΙ");
+        assert_eq!(to_upper("café"), "CAFÉ");
+    }
+}
@@ -0,0 +1,495 @@
+//! Hangul syllable decomposition, choseong keys and romanization.
+//!
+//! Korean search boxes match on partial input: while typing 한국 the text passes through
+//! ㅎ, 하, 한, 한ㄱ, 한구, and users also search by initial consonants alone (ㅎㄱ for
+//! 한국). Both work by comparing *jamo* (letter) sequences instead of syllables.
+//!
+//! All functions here speak **compatibility jamo** (U+3131..U+3163), the letters a
+//! keyboard produces, rather than the conjoining jamo that [`crate::unicode::nfd`]
+//! yields. Input may be precomposed, NFD or a mix of both.
+
+use unicode_normalization::UnicodeNormalization;
+
+const S_BASE: u32 = 0xAC00;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = 19 * N_COUNT;
+
+/// Initial consonants (choseong), in Unicode order.
+const LEADS: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ',
+    'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+/// Vowels (jungseong), in Unicode order.
+const VOWELS: [char; 21] = [
+    'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅘ', 'ㅙ', 'ㅚ', 'ㅛ', 'ㅜ', 'ㅝ', 'ㅞ',
+    'ㅟ', 'ㅠ', 'ㅡ', 'ㅢ', 'ㅣ',
+];
+
+/// Final consonants (jongseong), in Unicode order; index 0 is "no final".
+const TAILS: [Option<char>; 28] = [
+    None,
+    Some('ㄱ'),
+    Some('ㄲ'),
+    Some('ㄳ'),
+    Some('ㄴ'),
+    Some('ㄵ'),
+    Some('ㄶ'),
+    Some('ㄷ'),
+    Some('ㄹ'),
+    Some('ㄺ'),
+    Some('ㄻ'),
+    Some('ㄼ'),
+    Some('ㄽ'),
+    Some('ㄾ'),
+    Some('ㄿ'),
+    Some('ㅀ'),
+    Some('ㅁ'),
+    Some('ㅂ'),
+    Some('ㅄ'),
+    Some('ㅅ'),
+    Some('ㅆ'),
+    Some('ㅇ'),
+    Some('ㅈ'),
+    Some('ㅊ'),
+    Some('ㅋ'),
+    Some('ㅌ'),
+    Some('ㅍ'),
+    Some('ㅎ'),
+];
+
+/// Compound letters typed as two keys: `(compound, first, second)`.
+const COMPOUNDS: [(char, char, char); 18] = [
+    ('ㄳ', 'ㄱ', 'ㅅ'),
+    ('ㄵ', 'ㄴ', 'ㅈ'),
+    ('ㄶ', 'ㄴ', 'ㅎ'),
+    ('ㄺ', 'ㄹ', 'ㄱ'),
+    ('ㄻ', 'ㄹ', 'ㅁ'),
+    ('ㄼ', 'ㄹ', 'ㅂ'),
+    ('ㄽ', 'ㄹ', 'ㅅ'),
+    ('ㄾ', 'ㄹ', 'ㅌ'),
+    ('ㄿ', 'ㄹ', 'ㅍ'),
+    ('ㅀ', 'ㄹ', 'ㅎ'),
+    ('ㅄ', 'ㅂ', 'ㅅ'),
+    ('ㅘ', 'ㅗ', 'ㅏ'),
+    ('ㅙ', 'ㅗ', 'ㅐ'),
+    ('ㅚ', 'ㅗ', 'ㅣ'),
+    ('ㅝ', 'ㅜ', 'ㅓ'),
+    ('ㅞ', 'ㅜ', 'ㅔ'),
+    ('ㅟ', 'ㅜ', 'ㅣ'),
+    ('ㅢ', 'ㅡ', 'ㅣ'),
+];
+
+fn split_compound(c: char) -> Option<(char, char)> {
+    COMPOUNDS
+        .iter()
+        .find(|&&(compound, _, _)| compound == c)
+        .map(|&(_, a, b)| (a, b))
+}
+
+fn join_compound(a: char, b: char) -> Option<char> {
+    COMPOUNDS
+        .iter()
+        .find(|&&(_, x, y)| x == a && y == b)
+        .map(|&(compound, _, _)| compound)
+}
+
+fn lead_index(c: char) -> Option<usize> {
+    LEADS.iter().position(|&l| l == c)
+}
+
+fn vowel_index(c: char) -> Option<usize> {
+    VOWELS.iter().position(|&v| v == c)
+}
+
+fn tail_index(c: char) -> Option<usize> {
+    TAILS.iter().position(|&t| t == Some(c))
+}
+
+/// Whether `c` is a precomposed Hangul syllable (U+AC00..U+D7A3).
+pub fn is_syllable(c: char) -> bool {
+    (S_BASE..S_BASE + S_COUNT).contains(&(c as u32))
+}
+
+/// Split a precomposed syllable into compatibility jamo: `'한'` → `('ㅎ', 'ㅏ', Some('ㄴ'))`.
+pub fn decompose_syllable(c: char) -> Option<(char, char, Option<char>)> {
+    if !is_syllable(c) {
+        return None;
+    }
+    let s = c as u32 - S_BASE;
+    let lead = LEADS[(s / N_COUNT) as usize];
+    let vowel = VOWELS[((s % N_COUNT) / T_COUNT) as usize];
+    let tail = TAILS[(s % T_COUNT) as usize];
+    Some((lead, vowel, tail))
+}
+
+/// Build a syllable from compatibility jamo: `('ㅎ', 'ㅏ', Some('ㄴ'))` → `'한'`.
+///
+/// Returns `None` if a letter cannot appear in its position (e.g. ㄸ as a final).
+pub fn compose_syllable(lead: char, vowel: char, tail: Option<char>) -> Option<char> {
+    let l = lead_index(lead)? as u32;
+    let v = vowel_index(vowel)? as u32;
+    let t = match tail {
+        Some(t) => tail_index(t)? as u32,
+        None => 0,
+    };
+    char::from_u32(S_BASE + l * N_COUNT + v * T_COUNT + t)
+}
+
+/// Map a conjoining jamo (U+1100 block) to its compatibility letter.
+fn conjoining_to_compat(c: char) -> Option<char> {
+    let cp = c as u32;
+    match cp {
+        0x1100..=0x1112 => Some(LEADS[(cp - 0x1100) as usize]),
+        0x1161..=0x1175 => Some(VOWELS[(cp - 0x1161) as usize]),
+        0x11A8..=0x11C2 => TAILS[(cp - 0x11A7) as usize],
+        _ => None,
+    }
+}
+
+/// Decompose syllables into compatibility jamo: `"한국"` → `"ㅎㅏㄴㄱㅜㄱ"`.
+///
+/// Compound vowels and finals (ㅘ, ㄳ) are kept as single letters; see [`keystrokes`]
+/// to split them. Conjoining jamo are mapped to compatibility jamo; other text passes
+/// through unchanged.
+pub fn decompose(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() * 3);
+    for c in text.nfc() {
+        push_jamo(c, false, &mut out);
+    }
+    out
+}
+
+/// Like [`decompose`], but also split compound letters into the keys that type them:
+/// `"과"` → `"ㄱㅗㅏ"`, `"닭"` → `"ㄷㅏㄹㄱ"`.
+///
+/// A prefix of the keystroke sequence is what an autocomplete box sees while the user
+/// is still typing, so `keystrokes(query)` being a prefix of `keystrokes(candidate)` is
+/// the usual Korean prefix test.
+pub fn keystrokes(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() * 3);
+    for c in text.nfc() {
+        push_jamo(c, true, &mut out);
+    }
+    out
+}
+
+fn push_jamo(c: char, split: bool, out: &mut String) {
+    let mut push = |j: char| match split_compound(j) {
+        Some((a, b)) if split => {
+            out.push(a);
+            out.push(b);
+        }
+        _ => out.push(j),
+    };
+    if let Some((lead, vowel, tail)) = decompose_syllable(c) {
+        push(lead);
+        push(vowel);
+        if let Some(t) = tail {
+            push(t);
+        }
+    } else {
+        push(conjoining_to_compat(c).unwrap_or(c));
+    }
+}
+
+/// Compose jamo sequences into syllables, the way a Korean IME does:
+/// `"ㅎㅏㄴㄱㅜㄱ"` → `"한국"`, `"ㄱㅗㅏ"` → `"과"`.
+///
+/// Accepts the output of [`decompose`] or [`keystrokes`] as well as partially composed
+/// text (`"하ㄴ"` → `"한"`). A final consonant followed by a vowel moves to the next
+/// syllable (`"ㄷㅏㄹㄱㅣ"` → `"달기"`). Letters that cannot form a syllable are kept as is.
+pub fn compose(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut state = Composer::default();
+    for c in decompose(text).chars() {
+        if lead_index(c).is_some() || tail_index(c).is_some() {
+            state.consonant(c, &mut out);
+        } else if vowel_index(c).is_some() {
+            state.vowel(c, &mut out);
+        } else {
+            state.flush(&mut out);
+            out.push(c);
+        }
+    }
+    state.flush(&mut out);
+    out
+}
+
+/// The syllable being assembled by [`compose`].
+#[derive(Default)]
+struct Composer {
+    lead: Option<char>,
+    vowel: Option<char>,
+    tail: Option<char>,
+}
+
+impl Composer {
+    fn flush(&mut self, out: &mut String) {
+        match (self.lead.take(), self.vowel.take(), self.tail.take()) {
+            (Some(l), Some(v), t) => out.extend(compose_syllable(l, v, t)),
+            (l, v, _) => {
+                out.extend(l);
+                out.extend(v);
+            }
+        }
+    }
+
+    fn consonant(&mut self, c: char, out: &mut String) {
+        match (self.lead, self.vowel, self.tail) {
+            (Some(_), Some(_), None) if tail_index(c).is_some() => self.tail = Some(c),
+            (Some(_), Some(_), Some(t)) => match join_compound(t, c) {
+                Some(compound) if tail_index(compound).is_some() => self.tail = Some(compound),
+                _ => self.start(c, out),
+            },
+            _ => self.start(c, out),
+        }
+    }
+
+    fn vowel(&mut self, c: char, out: &mut String) {
+        match (self.lead, self.vowel, self.tail) {
+            (_, None, _) => self.vowel = Some(c),
+            (_, Some(v), None) => match join_compound(v, c) {
+                Some(compound) => self.vowel = Some(compound),
+                None => {
+                    self.flush(out);
+                    self.vowel = Some(c);
+                }
+            },
+            (lead, Some(v), Some(t)) => {
+                // The final consonant (or the second half of a compound final) becomes
+                // the initial of the next syllable.
+                let (keep, moved) = match split_compound(t) {
+                    Some((a, b)) => (Some(a), b),
+                    None => (None, t),
+                };
+                out.extend(lead.and_then(|l| compose_syllable(l, v, keep)));
+                self.lead = Some(moved);
+                self.vowel = Some(c);
+                self.tail = None;
+            }
+        }
+    }
+
+    fn start(&mut self, c: char, out: &mut String) {
+        self.flush(out);
+        if lead_index(c).is_some() {
+            self.lead = Some(c);
+        } else {
+            out.push(c);
+        }
+    }
+}
+
+/// Initial-consonant (choseong) key: `"한국"` → `"ㅎㄱ"`.
+///
+/// Each syllable is replaced by its initial consonant; everything else, including jamo
+/// already typed as consonants, passes through, so `choseong("한국") == choseong("ㅎㄱ")`.
+pub fn choseong(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.nfc() {
+        match decompose_syllable(c) {
+            Some((lead, _, _)) => out.push(lead),
+            None => out.push(conjoining_to_compat(c).unwrap_or(c)),
+        }
+    }
+    out
+}
+
+fn lead_roman(c: char) -> &'static str {
+    match c {
+        'ㄱ' => "g",
+        'ㄲ' => "kk",
+        'ㄴ' => "n",
+        'ㄷ' => "d",
+        'ㄸ' => "tt",
+        'ㄹ' => "r",
+        'ㅁ' => "m",
+        'ㅂ' => "b",
+        'ㅃ' => "pp",
+        'ㅅ' => "s",
+        'ㅆ' => "ss",
+        'ㅈ' => "j",
+        'ㅉ' => "jj",
+        'ㅊ' => "ch",
+        'ㅋ' => "k",
+        'ㅌ' => "t",
+        'ㅍ' => "p",
+        'ㅎ' => "h",
+        _ => "",
+    }
+}
+
+fn vowel_roman(c: char) -> &'static str {
+    const ROMAN: [&str; 21] = [
+        "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa", "wae", "oe", "yo", "u", "wo",
+        "we", "wi", "yu", "eu", "ui", "i",
+    ];
+    vowel_index(c).map_or("", |i| ROMAN[i])
+}
+
+/// How a final consonant is pronounced at the end of a syllable (the seven
+/// representative sounds).
+fn final_roman(c: char) -> &'static str {
+    match c {
+        'ㄱ' | 'ㄲ' | 'ㄳ' | 'ㄺ' | 'ㅋ' => "k",
+        'ㄴ' | 'ㄵ' | 'ㄶ' => "n",
+        'ㄷ' | 'ㅅ' | 'ㅆ' | 'ㅈ' | 'ㅊ' | 'ㅌ' | 'ㅎ' => "t",
+        'ㄹ' | 'ㄼ' | 'ㄽ' | 'ㄾ' | 'ㅀ' => "l",
+        'ㅁ' | 'ㄻ' => "m",
+        'ㅂ' | 'ㅄ' | 'ㄿ' | 'ㅍ' => "p",
+        'ㅇ' => "ng",
+        _ => "",
+    }
+}
+
+/// Romanize a final consonant given the initial of the next syllable; returns the
+/// letters to write and, if the sound change affects it, the next syllable's new initial.
+fn final_before(tail: char, next: Option<char>) -> (&'static str, Option<char>) {
+    let Some(next) = next else {
+        return (final_roman(tail), None);
+    };
+    if next == 'ㅇ' {
+        // Liaison: the final is pronounced as the next syllable's initial.
+        return match (tail, split_compound(tail)) {
+            ('ㅇ', _) => ("ng", None),
+            ('ㅎ', _) => ("", None),
+            (_, Some((a, 'ㅎ'))) => ("", Some(a)),
+            (_, Some((a, b))) => (final_roman(a), Some(b)),
+            (t, None) => ("", Some(t)),
+        };
+    }
+    if let Some(rest) = match tail {
+        'ㅎ' => Some(""),
+        'ㄶ' => Some("n"),
+        'ㅀ' => Some("l"),
+        _ => None,
+    } {
+        // ㅎ aspirates a following plain stop.
+        return match next {
+            'ㄱ' => (rest, Some('ㅋ')),
+            'ㄷ' => (rest, Some('ㅌ')),
+            'ㅈ' => (rest, Some('ㅊ')),
+            'ㄴ' if tail == 'ㅀ' => ("l", Some('ㄹ')),
+            'ㄴ' => ("n", None),
+            _ if rest.is_empty() => ("t", None),
+            _ => (rest, None),
+        };
+    }
+    match (final_roman(tail), next) {
+        // Nasalization.
+        ("k", 'ㄴ' | 'ㅁ') => ("ng", None),
+        ("t", 'ㄴ' | 'ㅁ') => ("n", None),
+        ("p", 'ㄴ' | 'ㅁ') => ("m", None),
+        ("k", 'ㄹ') => ("ng", Some('ㄴ')),
+        ("p", 'ㄹ') => ("m", Some('ㄴ')),
+        (sound @ ("m" | "ng"), 'ㄹ') => (sound, Some('ㄴ')),
+        // Lateralization: ㄴ and ㄹ next to each other are both pronounced [l].
+        ("n", 'ㄹ') => ("l", None),
+        ("l", 'ㄴ') => ("l", Some('ㄹ')),
+        (sound, _) => (sound, None),
+    }
+}
+
+/// Romanize Hangul with the Revised Romanization of Korean: `"한국어"` → `"hangugeo"`.
+///
+/// The common sound changes between adjacent syllables are applied: liaison
+/// (한국어 → hangugeo), nasalization (국민 → gungmin, 종로 → jongno), lateralization
+/// (신라 → silla) and ㅎ aspiration (좋고 → joko). Output is lowercase and other text
+/// passes through unchanged. Rules that depend on morphology (e.g. whether ㅎ
+/// aspiration is written in nouns) are not modelled.
+pub fn romanize(text: &str) -> String {
+    let chars: Vec<char> = text.nfc().collect();
+    let mut out = String::with_capacity(chars.len() * 3);
+    let mut lead_override = None;
+    let mut prev_final_l = false;
+    for (i, &c) in chars.iter().enumerate() {
+        let Some((lead, vowel, tail)) = decompose_syllable(c) else {
+            out.push(c);
+            lead_override = None;
+            prev_final_l = false;
+            continue;
+        };
+        let lead = lead_override.take().unwrap_or(lead);
+        // ㄹ is written `l` after a final `l` (ㄹㄹ → ll).
+        out.push_str(if lead == 'ㄹ' && prev_final_l {
+            "l"
+        } else {
+            lead_roman(lead)
+        });
+        out.push_str(vowel_roman(vowel));
+        prev_final_l = false;
+        if let Some(t) = tail {
+            let next = chars
+                .get(i + 1)
+                .and_then(|&n| decompose_syllable(n))
+                .map(|(l, _, _)| l);
+            let (written, next_lead) = final_before(t, next);
+            out.push_str(written);
+            lead_override = next_lead;
+            prev_final_l = written == "l";
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_syllable_roundtrip() {
+        assert_eq!(decompose_syllable('한'), Some(('ㅎ', 'ㅏ', Some('ㄴ'))));
+        assert_eq!(decompose_syllable('가'), Some(('ㄱ', 'ㅏ', None)));
+        assert_eq!(compose_syllable('ㄷ', 'ㅏ', Some('ㄺ')), Some('닭'));
+        assert_eq!(compose_syllable('ㄱ', 'ㅏ', Some('ㄸ')), None);
+        assert_eq!(decompose_syllable('a'), None);
+    }
+
+    #[test]
+    fn test_decompose_and_keystrokes() {
+        assert_eq!(decompose("한국"), "ㅎㅏㄴㄱㅜㄱ");
+        assert_eq!(decompose("과 닭"), "ㄱㅘ ㄷㅏㄺ");
+        assert_eq!(keystrokes("과 닭"), "ㄱㅗㅏ ㄷㅏㄹㄱ");
+        // NFD input (conjoining jamo) gives the same result.
+        assert_eq!(decompose(&crate::unicode::nfd("한국")), "ㅎㅏㄴㄱㅜㄱ");
+        assert!(keystrokes("한국").starts_with(&keystrokes("한구")));
+    }
+
+    #[test]
+    fn test_compose() {
+        assert_eq!(compose("ㅎㅏㄴㄱㅜㄱ"), "한국");
+        assert_eq!(compose("ㄱㅗㅏ ㄷㅏㄹㄱ"), "과 닭");
+        assert_eq!(compose("ㄷㅏㄹㄱㅣ"), "달기");
+        assert_eq!(compose("하ㄴ"), "한");
+        assert_eq!(compose("ㅎㄱ"), "ㅎㄱ");
+        for s in ["대한민국", "읽었다", "괜찮아요", "Hello, 세계!"] {
+            assert_eq!(compose(&keystrokes(s)), s);
+        }
+    }
+
+    #[test]
+    fn test_choseong() {
+        assert_eq!(choseong("한국"), "ㅎㄱ");
+        assert_eq!(choseong("대한민국 2024"), "ㄷㅎㅁㄱ 2024");
+        assert_eq!(choseong("ㅎㄱ"), "ㅎㄱ");
+    }
+
+    #[test]
+    fn test_romanize() {
+        assert_eq!(romanize("한국어"), "hangugeo");
+        assert_eq!(romanize("서울"), "seoul");
+        assert_eq!(romanize("부산"), "busan");
+        assert_eq!(romanize("국민"), "gungmin");
+        assert_eq!(romanize("종로"), "jongno");
+        assert_eq!(romanize("신라"), "silla");
+        assert_eq!(romanize("설날"), "seollal");
+        assert_eq!(romanize("독립"), "dongnip");
+        assert_eq!(romanize("좋고"), "joko");
+        assert_eq!(romanize("닭"), "dak");
+        assert_eq!(romanize("읽어"), "ilgeo");
+        assert_eq!(romanize("안녕하세요, 세계!"), "annyeonghaseyo, segye!");
+    }
+}
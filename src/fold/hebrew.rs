@@ -0,0 +1,134 @@
+//! Hebrew normalization for search.
+//!
+//! Most modern Hebrew is written without vowel points (niqqud), while liturgical and
+//! educational text carries both niqqud and cantillation marks (te'amim). A query typed
+//! without points should still match pointed text, so both are removable. Final letter
+//! forms (ך ם ן ף ץ) can optionally be folded to their medial forms, which helps prefix
+//! matching ("autocomplete" on a partially typed word ends in a medial letter).
+
+use unicode_normalization::UnicodeNormalization;
+
+/// Which Hebrew normalization rules to apply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HebrewConfig {
+    /// Remove vowel points and related marks (U+05B0..U+05BD, U+05BF, U+05C1, U+05C2,
+    /// U+05C4, U+05C5, U+05C7): sheva, patah, qamats, dagesh, shin/sin dots, …
+    pub niqqud: bool,
+    /// Remove cantillation marks (te'amim, U+0591..U+05AF).
+    pub teamim: bool,
+    /// Fold final forms to medial letters: ך→כ ם→מ ן→נ ף→פ ץ→צ.
+    pub final_forms: bool,
+    /// Map geresh (U+05F3) to `'` and gershayim (U+05F4) to `"`.
+    ///
+    /// Hebrew abbreviations (צה״ל, ת״א) are typed with ASCII quotes far more often than with
+    /// the dedicated punctuation.
+    pub geresh: bool,
+}
+
+impl Default for HebrewConfig {
+    /// Strip niqqud and te'amim, normalize geresh/gershayim, keep final forms.
+    fn default() -> Self {
+        Self {
+            niqqud: true,
+            teamim: true,
+            final_forms: false,
+            geresh: true,
+        }
+    }
+}
+
+fn is_teamim(c: char) -> bool {
+    matches!(c, '\u{0591}'..='\u{05AF}')
+}
+
+fn is_niqqud(c: char) -> bool {
+    matches!(c, '\u{05B0}'..='\u{05BD}')
+        || matches!(
+            c,
+            '\u{05BF}' | '\u{05C1}' | '\u{05C2}' | '\u{05C4}' | '\u{05C5}' | '\u{05C7}'
+        )
+}
+
+fn medial_form(c: char) -> char {
+    match c {
+        '\u{05DA}' => '\u{05DB}', // ך → כ
+        '\u{05DD}' => '\u{05DE}', // ם → מ
+        '\u{05DF}' => '\u{05E0}', // ן → נ
+        '\u{05E3}' => '\u{05E4}', // ף → פ
+        '\u{05E5}' => '\u{05E6}', // ץ → צ
+        c => c,
+    }
+}
+
+/// Normalize Hebrew text with [`HebrewConfig::default`].
+pub fn normalize(text: &str) -> String {
+    normalize_with(text, &HebrewConfig::default())
+}
+
+/// Normalize Hebrew text with an explicit rule set.
+///
+/// Input is decomposed first so that precomposed presentation forms (e.g. U+FB2A שׁ)
+/// lose their points too; the result is recomposed (NFC), so non-Hebrew text comes
+/// back NFC-normalized. Text that is already NFC and has no Hebrew is returned
+/// unchanged.
+pub fn normalize_with(text: &str, cfg: &HebrewConfig) -> String {
+    let mut out = String::with_capacity(text.len());
+    normalize_into(text, cfg, &mut out);
+    out
+}
+
+/// Like [`normalize_with`], but writes into an existing `String`.
+pub fn normalize_into(text: &str, cfg: &HebrewConfig, out: &mut String) {
+    out.clear();
+    out.reserve(text.len());
+    let filtered = text.nfd().filter_map(|c| {
+        if (cfg.niqqud && is_niqqud(c)) || (cfg.teamim && is_teamim(c)) {
+            return None;
+        }
+        Some(match c {
+            '\u{05F3}' if cfg.geresh => '\'',
+            '\u{05F4}' if cfg.geresh => '"',
+            c if cfg.final_forms => medial_form(c),
+            c => c,
+        })
+    });
+    out.extend(filtered.nfc());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strips_niqqud_and_teamim() {
+        // Genesis 1:1, fully pointed and cantillated.
+        let pointed = "בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים";
+        assert_eq!(normalize(pointed), "בראשית ברא אלהים");
+    }
+
+    #[test]
+    fn test_separate_toggles() {
+        let keep_niqqud = HebrewConfig {
+            niqqud: false,
+            ..HebrewConfig::default()
+        };
+        assert_eq!(normalize_with("בָּרָ֣א", &keep_niqqud), "בָּרָא");
+    }
+
+    #[test]
+    fn test_presentation_forms_are_decomposed() {
+        assert_eq!(normalize("\u{FB2A}לום"), "שלום");
+    }
+
+    #[test]
+    fn test_final_forms_and_geresh() {
+        let cfg = HebrewConfig {
+            final_forms: true,
+            ..HebrewConfig::default()
+        };
+        assert_eq!(normalize_with("מלך שלום", &cfg), "מלכ שלומ");
+        assert_eq!(normalize("צה\u{05F4}ל ג\u{05F3}"), "צה\"ל ג'");
+        assert_eq!(normalize("מלך"), "מלך");
+    }
+}
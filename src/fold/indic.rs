@@ -0,0 +1,338 @@
+//! Normalization for the Brahmic scripts of India (Devanagari, Bengali, Gurmukhi,
+//! Gujarati, Oriya, Tamil, Telugu, Kannada, Malayalam).
+//!
+//! The same visible text can be encoded several ways in these scripts, and keys built
+//! from raw input miss matches that a reader would consider identical:
+//!
+//! - nukta letters: क़ (U+0958) vs क + ़ (NFC picks the decomposed form);
+//! - vowel letters typed as a base vowel + sign: अ + ा instead of आ;
+//! - old-style Malayalam chillus and Bengali khanda ta: ന + ് + ZWJ instead of ൻ,
+//!   ত + ্ + ZWJ instead of ৎ;
+//! - nasals: a homorganic nasal + virama vs anusvara (हिन्दी / हिंदी), and chandrabindu
+//!   vs anusvara (optional, lossy);
+//! - ZWJ/ZWNJ, which select half forms and explicit viramas after a virama but are
+//!   invisible noise anywhere else.
+//!
+//! The rules follow the spirit of the IndicNLP normalizers. The nine Unicode blocks
+//! share one layout, so rules are written once against offsets within a block and only
+//! enabled for the scripts where they apply.
+
+use unicode_normalization::UnicodeNormalization;
+
+const ZWNJ: char = '\u{200C}';
+const ZWJ: char = '\u{200D}';
+
+// Offsets within an Indic block.
+const CHANDRABINDU: u32 = 0x01;
+const ANUSVARA: u32 = 0x02;
+const NUKTA: u32 = 0x3C;
+const VIRAMA: u32 = 0x4D;
+
+/// What to do with ZERO WIDTH JOINER / NON-JOINER next to Indic letters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JoinerPolicy {
+    /// Leave all joiners alone.
+    Keep,
+    /// Keep joiners adjacent to a virama (half forms, explicit virama, eyelash ra) and
+    /// remove the others.
+    #[default]
+    Orthographic,
+    /// Remove all joiners next to Indic letters.
+    Remove,
+}
+
+/// Which Indic normalization rules to apply.
+///
+/// Joiners that do not touch an Indic letter (emoji ZWJ sequences, Persian ZWNJ) are
+/// never modified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IndicConfig {
+    /// Rewrite vowel letters typed as a base vowel + vowel sign (अ + ा → आ) and
+    /// old-style chillu / khanda ta sequences to their atomic letters.
+    pub canonical_letters: bool,
+    /// Remove the nukta (ज़ → ज). Lossy: ज़ and ज are different sounds.
+    pub remove_nukta: bool,
+    /// Replace chandrabindu with anusvara (हँसी → हंसी). Lossy, but the two are used
+    /// interchangeably in much informal Hindi.
+    pub chandrabindu: bool,
+    /// Replace a nasal consonant + virama before a consonant of the same class with
+    /// anusvara (हिन्दी → हिंदी, सम्बन्ध → संबंध). Not applied to Tamil and Gurmukhi,
+    /// which do not use anusvara this way.
+    pub nasals: bool,
+    /// ZWJ/ZWNJ handling.
+    pub joiners: JoinerPolicy,
+}
+
+impl IndicConfig {
+    /// Canonicalization plus the common lossy folds used for search keys
+    /// (chandrabindu and nasals → anusvara). Nuktas are kept.
+    pub fn search() -> Self {
+        Self {
+            chandrabindu: true,
+            nasals: true,
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for IndicConfig {
+    /// Encoding-level canonicalization only: vowel letters, chillus, orthographic joiners.
+    fn default() -> Self {
+        Self {
+            canonical_letters: true,
+            remove_nukta: false,
+            chandrabindu: false,
+            nasals: false,
+            joiners: JoinerPolicy::Orthographic,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Block {
+    Devanagari,
+    Bengali,
+    Gurmukhi,
+    Gujarati,
+    Oriya,
+    Tamil,
+    Telugu,
+    Kannada,
+    Malayalam,
+}
+
+/// The Indic block of `c` and `c`'s offset within it.
+fn block(c: char) -> Option<(Block, u32)> {
+    let cp = c as u32;
+    if !(0x0900..0x0D80).contains(&cp) {
+        return None;
+    }
+    let b = match (cp - 0x0900) / 0x80 {
+        0 => Block::Devanagari,
+        1 => Block::Bengali,
+        2 => Block::Gurmukhi,
+        3 => Block::Gujarati,
+        4 => Block::Oriya,
+        5 => Block::Tamil,
+        6 => Block::Telugu,
+        7 => Block::Kannada,
+        _ => Block::Malayalam,
+    };
+    Some((b, cp & 0x7F))
+}
+
+fn in_block(c: char, b: Block, offset: u32) -> char {
+    let base = 0x0900 + 0x80 * b as u32;
+    char::from_u32(base + offset).unwrap_or(c)
+}
+
+fn is_virama(c: char) -> bool {
+    matches!(block(c), Some((_, VIRAMA)))
+}
+
+/// The nasal consonant of the class (varga) that the consonant at `offset` belongs to.
+fn homorganic_nasal(offset: u32) -> Option<u32> {
+    match offset {
+        0x15..=0x18 => Some(0x19), // क ख ग घ → ङ
+        0x1A..=0x1D => Some(0x1E), // च छ ज झ → ञ
+        0x1F..=0x22 => Some(0x23), // ट ठ ड ढ → ण
+        0x24..=0x27 => Some(0x28), // त थ द ध → न
+        0x2A..=0x2D => Some(0x2E), // प फ ब भ → म
+        _ => None,
+    }
+}
+
+/// Independent vowel + vowel sign sequences that should be a single vowel letter (the
+/// "do not use" sequences of the Unicode Standard's South Asian scripts chapter).
+const VOWEL_LETTERS: &[(char, char, char)] = &[
+    ('\u{0905}', '\u{093E}', '\u{0906}'), // अ + ा → आ
+    ('\u{0905}', '\u{0946}', '\u{0904}'), // अ + ॆ → ऄ
+    ('\u{0905}', '\u{0949}', '\u{0911}'), // अ + ॉ → ऑ
+    ('\u{0905}', '\u{094A}', '\u{0912}'), // अ + ॊ → ऒ
+    ('\u{0905}', '\u{094B}', '\u{0913}'), // अ + ो → ओ
+    ('\u{0905}', '\u{094C}', '\u{0914}'), // अ + ौ → औ
+    ('\u{090F}', '\u{0945}', '\u{090D}'), // ए + ॅ → ऍ
+    ('\u{090F}', '\u{0946}', '\u{090E}'), // ए + ॆ → ऎ
+    ('\u{090F}', '\u{0947}', '\u{0910}'), // ए + े → ऐ
+    ('\u{0985}', '\u{09BE}', '\u{0986}'), // অ + া → আ
+    ('\u{0A85}', '\u{0ABE}', '\u{0A86}'), // અ + ા → આ
+    ('\u{0A85}', '\u{0AC5}', '\u{0A8D}'), // અ + ૅ → ઍ
+    ('\u{0A85}', '\u{0AC7}', '\u{0A8F}'), // અ + ે → એ
+    ('\u{0A85}', '\u{0AC8}', '\u{0A90}'), // અ + ૈ → ઐ
+    ('\u{0A85}', '\u{0AC9}', '\u{0A91}'), // અ + ૉ → ઑ
+    ('\u{0A85}', '\u{0ACB}', '\u{0A93}'), // અ + ો → ઓ
+    ('\u{0A85}', '\u{0ACC}', '\u{0A94}'), // અ + ૌ → ઔ
+];
+
+/// Consonant + virama + ZWJ sequences that have an atomic encoding.
+const ATOMIC_DEAD_CONSONANTS: &[(char, char)] = &[
+    ('\u{09A4}', '\u{09CE}'), // ত + ্ + ZWJ → ৎ (khanda ta)
+    ('\u{0D23}', '\u{0D7A}'), // ണ + ് + ZWJ → ൺ
+    ('\u{0D28}', '\u{0D7B}'), // ന + ് + ZWJ → ൻ
+    ('\u{0D30}', '\u{0D7C}'), // ര + ് + ZWJ → ർ
+    ('\u{0D32}', '\u{0D7D}'), // ല + ് + ZWJ → ൽ
+    ('\u{0D33}', '\u{0D7E}'), // ള + ് + ZWJ → ൾ
+    ('\u{0D15}', '\u{0D7F}'), // ക + ് + ZWJ → ൿ
+];
+
+/// Normalize Indic text with [`IndicConfig::default`].
+pub fn normalize(text: &str) -> String {
+    normalize_with(text, &IndicConfig::default())
+}
+
+/// Normalize Indic text with an explicit rule set.
+///
+/// Input is composed (NFC) first, which also splits precomposed nukta letters into
+/// consonant + nukta. Text outside the Indic blocks passes through unchanged.
+pub fn normalize_with(text: &str, cfg: &IndicConfig) -> String {
+    let mut out = String::with_capacity(text.len());
+    normalize_into(text, cfg, &mut out);
+    out
+}
+
+/// Like [`normalize_with`], but writes into an existing `String`.
+pub fn normalize_into(text: &str, cfg: &IndicConfig, out: &mut String) {
+    out.clear();
+    out.reserve(text.len());
+    let chars: Vec<char> = text.nfc().collect();
+    let mut res: Vec<char> = Vec::with_capacity(chars.len());
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c == ZWJ || c == ZWNJ {
+            let prev = res.last().copied();
+            let next = chars.get(i + 1).copied();
+            if c == ZWJ && cfg.canonical_letters {
+                if let [.., cons, virama] = res[..] {
+                    if is_virama(virama) {
+                        if let Some(&(_, atomic)) =
+                            ATOMIC_DEAD_CONSONANTS.iter().find(|&&(k, _)| k == cons)
+                        {
+                            res.truncate(res.len() - 2);
+                            res.push(atomic);
+                            continue;
+                        }
+                    }
+                }
+            }
+            let touches_indic = prev.and_then(block).is_some() || next.and_then(block).is_some();
+            let keep = match cfg.joiners {
+                _ if !touches_indic => true,
+                JoinerPolicy::Keep => true,
+                JoinerPolicy::Orthographic => {
+                    prev.is_some_and(is_virama) || next.is_some_and(is_virama)
+                }
+                JoinerPolicy::Remove => false,
+            };
+            if keep {
+                res.push(c);
+            }
+            continue;
+        }
+
+        let Some((b, offset)) = block(c) else {
+            res.push(c);
+            continue;
+        };
+
+        if offset == NUKTA && cfg.remove_nukta {
+            continue;
+        }
+        if offset == CHANDRABINDU
+            && cfg.chandrabindu
+            && matches!(
+                b,
+                Block::Devanagari | Block::Bengali | Block::Gujarati | Block::Oriya | Block::Telugu
+            )
+        {
+            res.push(in_block(c, b, ANUSVARA));
+            continue;
+        }
+        if cfg.canonical_letters {
+            if let Some(&prev) = res.last() {
+                if let Some(&(_, _, letter)) = VOWEL_LETTERS
+                    .iter()
+                    .find(|&&(base, sign, _)| base == prev && sign == c)
+                {
+                    res.pop();
+                    res.push(letter);
+                    continue;
+                }
+            }
+        }
+        if cfg.nasals && !matches!(b, Block::Tamil | Block::Gurmukhi) {
+            if let (Some(nasal), [.., n, v]) = (homorganic_nasal(offset), &res[..]) {
+                if *n == in_block(c, b, nasal) && *v == in_block(c, b, VIRAMA) {
+                    res.truncate(res.len() - 2);
+                    res.push(in_block(c, b, ANUSVARA));
+                }
+            }
+        }
+        res.push(c);
+    }
+    out.extend(res);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nukta_forms_agree() {
+        // क़ (precomposed, composition-excluded) vs क + ़.
+        assert_eq!(normalize("\u{0958}"), normalize("\u{0915}\u{093C}"));
+        let cfg = IndicConfig {
+            remove_nukta: true,
+            ..IndicConfig::default()
+        };
+        assert_eq!(normalize_with("ज़रूर", &cfg), "जरूर");
+        assert_eq!(normalize("ज़रूर"), "ज\u{093C}रूर");
+    }
+
+    #[test]
+    fn test_vowel_letters() {
+        assert_eq!(normalize("\u{0905}\u{093E}म"), "आम");
+        assert_eq!(normalize("\u{090F}\u{0947}सा"), "ऐसा");
+        assert_eq!(normalize("\u{0985}\u{09BE}মি"), "আমি");
+    }
+
+    #[test]
+    fn test_atomic_chillu_and_khanda_ta() {
+        assert_eq!(normalize("അവന്\u{200D}"), "അവൻ");
+        assert_eq!(normalize("উ\u{09A4}\u{09CD}\u{200D}সব"), "উৎসব");
+    }
+
+    #[test]
+    fn test_joiners() {
+        // ZWNJ after virama selects an explicit virama: kept.
+        assert_eq!(normalize("क्\u{200C}ष"), "क्\u{200C}ष");
+        // Eyelash ra: RA + ZWJ + VIRAMA.
+        assert_eq!(normalize("र\u{200D}्य"), "र\u{200D}्य");
+        // Stray joiners inside a word are removed.
+        assert_eq!(normalize("न\u{200D}मस\u{200C}ते"), "नमसते");
+        // Emoji ZWJ sequences and Persian ZWNJ are not Indic and are untouched.
+        assert_eq!(
+            normalize("👨\u{200D}👩 می\u{200C}خواهم"),
+            "👨\u{200D}👩 می\u{200C}خواهم"
+        );
+        let remove = IndicConfig {
+            joiners: JoinerPolicy::Remove,
+            ..IndicConfig::default()
+        };
+        assert_eq!(normalize_with("क्\u{200C}ष", &remove), "क्ष");
+    }
+
+    #[test]
+    fn test_search_nasal_folding() {
+        let cfg = IndicConfig::search();
+        assert_eq!(normalize_with("हिन्दी", &cfg), "हिंदी");
+        assert_eq!(normalize_with("सम्बन्ध", &cfg), "संबंध");
+        assert_eq!(normalize_with("हँसी", &cfg), "हंसी");
+        // न + virama before a consonant of another class is a real conjunct.
+        assert_eq!(normalize_with("अन्य", &cfg), "अन्य");
+        // Tamil keeps its nasal conjuncts.
+        assert_eq!(normalize_with("பந்து", &cfg), "பந்து");
+        assert_eq!(normalize("हिन्दी"), "हिन्दी");
+    }
+}
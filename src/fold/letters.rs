@@ -0,0 +1,424 @@
+//! Base-letter table for [`super::fold_letters`].
+//!
+//! Only letters whose base cannot be recovered by canonical decomposition (NFD) plus
+//! combining-mark removal are listed here: strokes, hooks, bars, ligatures, and a few
+//! historical letters. Everything else (é, ñ, ǘ, ά, ё, …) is handled by decomposition.
+//!
+//! Latin entries fold to ASCII, in the spirit of Lucene's `ASCIIFoldingFilter`. Greek and
+//! Cyrillic entries fold to the unadorned letter of the *same* script; no transliteration.
+//!
+//! Sorted by code point (binary searched).
+
+pub(super) static BASE_LETTERS: &[(char, &str)] = &[
+    ('\u{00C6}', "AE"),  // Æ LATIN CAPITAL LETTER AE
+    ('\u{00D0}', "D"),   // Ð LATIN CAPITAL LETTER ETH
+    ('\u{00D8}', "O"),   // Ø LATIN CAPITAL LETTER O WITH STROKE
+    ('\u{00DE}', "TH"),  // Þ LATIN CAPITAL LETTER THORN
+    ('\u{00DF}', "ss"),  // ß LATIN SMALL LETTER SHARP S
+    ('\u{00E6}', "ae"),  // æ LATIN SMALL LETTER AE
+    ('\u{00F0}', "d"),   // ð LATIN SMALL LETTER ETH
+    ('\u{00F8}', "o"),   // ø LATIN SMALL LETTER O WITH STROKE
+    ('\u{00FE}', "th"),  // þ LATIN SMALL LETTER THORN
+    ('\u{0110}', "D"),   // Đ LATIN CAPITAL LETTER D WITH STROKE
+    ('\u{0111}', "d"),   // đ LATIN SMALL LETTER D WITH STROKE
+    ('\u{0126}', "H"),   // Ħ LATIN CAPITAL LETTER H WITH STROKE
+    ('\u{0127}', "h"),   // ħ LATIN SMALL LETTER H WITH STROKE
+    ('\u{0131}', "i"),   // ı LATIN SMALL LETTER DOTLESS I
+    ('\u{0132}', "IJ"),  // Ĳ LATIN CAPITAL LIGATURE IJ
+    ('\u{0133}', "ij"),  // ĳ LATIN SMALL LIGATURE IJ
+    ('\u{0138}', "q"),   // ĸ LATIN SMALL LETTER KRA
+    ('\u{013F}', "L"),   // Ŀ LATIN CAPITAL LETTER L WITH MIDDLE DOT
+    ('\u{0140}', "l"),   // ŀ LATIN SMALL LETTER L WITH MIDDLE DOT
+    ('\u{0141}', "L"),   // Ł LATIN CAPITAL LETTER L WITH STROKE
+    ('\u{0142}', "l"),   // ł LATIN SMALL LETTER L WITH STROKE
+    ('\u{014A}', "N"),   // Ŋ LATIN CAPITAL LETTER ENG
+    ('\u{014B}', "n"),   // ŋ LATIN SMALL LETTER ENG
+    ('\u{0152}', "OE"),  // Œ LATIN CAPITAL LIGATURE OE
+    ('\u{0153}', "oe"),  // œ LATIN SMALL LIGATURE OE
+    ('\u{0166}', "T"),   // Ŧ LATIN CAPITAL LETTER T WITH STROKE
+    ('\u{0167}', "t"),   // ŧ LATIN SMALL LETTER T WITH STROKE
+    ('\u{017F}', "s"),   // ſ LATIN SMALL LETTER LONG S
+    ('\u{0180}', "b"),   // ƀ LATIN SMALL LETTER B WITH STROKE
+    ('\u{0181}', "B"),   // Ɓ LATIN CAPITAL LETTER B WITH HOOK
+    ('\u{0182}', "B"),   // Ƃ LATIN CAPITAL LETTER B WITH TOPBAR
+    ('\u{0183}', "b"),   // ƃ LATIN SMALL LETTER B WITH TOPBAR
+    ('\u{0187}', "C"),   // Ƈ LATIN CAPITAL LETTER C WITH HOOK
+    ('\u{0188}', "c"),   // ƈ LATIN SMALL LETTER C WITH HOOK
+    ('\u{018A}', "D"),   // Ɗ LATIN CAPITAL LETTER D WITH HOOK
+    ('\u{018B}', "D"),   // Ƌ LATIN CAPITAL LETTER D WITH TOPBAR
+    ('\u{018C}', "d"),   // ƌ LATIN SMALL LETTER D WITH TOPBAR
+    ('\u{0191}', "F"),   // Ƒ LATIN CAPITAL LETTER F WITH HOOK
+    ('\u{0192}', "f"),   // ƒ LATIN SMALL LETTER F WITH HOOK
+    ('\u{0193}', "G"),   // Ɠ LATIN CAPITAL LETTER G WITH HOOK
+    ('\u{0195}', "hv"),  // ƕ LATIN SMALL LETTER HV
+    ('\u{0197}', "I"),   // Ɨ LATIN CAPITAL LETTER I WITH STROKE
+    ('\u{0198}', "K"),   // Ƙ LATIN CAPITAL LETTER K WITH HOOK
+    ('\u{0199}', "k"),   // ƙ LATIN SMALL LETTER K WITH HOOK
+    ('\u{019A}', "l"),   // ƚ LATIN SMALL LETTER L WITH BAR
+    ('\u{019D}', "N"),   // Ɲ LATIN CAPITAL LETTER N WITH LEFT HOOK
+    ('\u{019E}', "n"),   // ƞ LATIN SMALL LETTER N WITH LONG RIGHT LEG
+    ('\u{019F}', "O"),   // Ɵ LATIN CAPITAL LETTER O WITH MIDDLE TILDE
+    ('\u{01A2}', "OI"),  // Ƣ LATIN CAPITAL LETTER OI
+    ('\u{01A3}', "oi"),  // ƣ LATIN SMALL LETTER OI
+    ('\u{01A4}', "P"),   // Ƥ LATIN CAPITAL LETTER P WITH HOOK
+    ('\u{01A5}', "p"),   // ƥ LATIN SMALL LETTER P WITH HOOK
+    ('\u{01AB}', "t"),   // ƫ LATIN SMALL LETTER T WITH PALATAL HOOK
+    ('\u{01AC}', "T"),   // Ƭ LATIN CAPITAL LETTER T WITH HOOK
+    ('\u{01AD}', "t"),   // ƭ LATIN SMALL LETTER T WITH HOOK
+    ('\u{01AE}', "T"),   // Ʈ LATIN CAPITAL LETTER T WITH RETROFLEX HOOK
+    ('\u{01B2}', "V"),   // Ʋ LATIN CAPITAL LETTER V WITH HOOK
+    ('\u{01B3}', "Y"),   // Ƴ LATIN CAPITAL LETTER Y WITH HOOK
+    ('\u{01B4}', "y"),   // ƴ LATIN SMALL LETTER Y WITH HOOK
+    ('\u{01B5}', "Z"),   // Ƶ LATIN CAPITAL LETTER Z WITH STROKE
+    ('\u{01B6}', "z"),   // ƶ LATIN SMALL LETTER Z WITH STROKE
+    ('\u{01C4}', "DZ"),  // Ǆ LATIN CAPITAL LETTER DZ WITH CARON
+    ('\u{01C5}', "Dz"),  // ǅ LATIN CAPITAL LETTER D WITH SMALL LETTER Z WITH CARON
+    ('\u{01C6}', "dz"),  // ǆ LATIN SMALL LETTER DZ WITH CARON
+    ('\u{01C7}', "LJ"),  // Ǉ LATIN CAPITAL LETTER LJ
+    ('\u{01C8}', "Lj"),  // ǈ LATIN CAPITAL LETTER L WITH SMALL LETTER J
+    ('\u{01C9}', "lj"),  // ǉ LATIN SMALL LETTER LJ
+    ('\u{01CA}', "NJ"),  // Ǌ LATIN CAPITAL LETTER NJ
+    ('\u{01CB}', "Nj"),  // ǋ LATIN CAPITAL LETTER N WITH SMALL LETTER J
+    ('\u{01CC}', "nj"),  // ǌ LATIN SMALL LETTER NJ
+    ('\u{01E2}', "AE"),  // Ǣ LATIN CAPITAL LETTER AE WITH MACRON
+    ('\u{01E3}', "ae"),  // ǣ LATIN SMALL LETTER AE WITH MACRON
+    ('\u{01E4}', "G"),   // Ǥ LATIN CAPITAL LETTER G WITH STROKE
+    ('\u{01E5}', "g"),   // ǥ LATIN SMALL LETTER G WITH STROKE
+    ('\u{01F1}', "DZ"),  // Ǳ LATIN CAPITAL LETTER DZ
+    ('\u{01F2}', "Dz"),  // ǲ LATIN CAPITAL LETTER D WITH SMALL LETTER Z
+    ('\u{01F3}', "dz"),  // ǳ LATIN SMALL LETTER DZ
+    ('\u{01FC}', "AE"),  // Ǽ LATIN CAPITAL LETTER AE WITH ACUTE
+    ('\u{01FD}', "ae"),  // ǽ LATIN SMALL LETTER AE WITH ACUTE
+    ('\u{01FE}', "O"),   // Ǿ LATIN CAPITAL LETTER O WITH STROKE AND ACUTE
+    ('\u{01FF}', "o"),   // ǿ LATIN SMALL LETTER O WITH STROKE AND ACUTE
+    ('\u{0220}', "N"),   // Ƞ LATIN CAPITAL LETTER N WITH LONG RIGHT LEG
+    ('\u{0221}', "d"),   // ȡ LATIN SMALL LETTER D WITH CURL
+    ('\u{0222}', "OU"),  // Ȣ LATIN CAPITAL LETTER OU
+    ('\u{0223}', "ou"),  // ȣ LATIN SMALL LETTER OU
+    ('\u{0224}', "Z"),   // Ȥ LATIN CAPITAL LETTER Z WITH HOOK
+    ('\u{0225}', "z"),   // ȥ LATIN SMALL LETTER Z WITH HOOK
+    ('\u{0234}', "l"),   // ȴ LATIN SMALL LETTER L WITH CURL
+    ('\u{0235}', "n"),   // ȵ LATIN SMALL LETTER N WITH CURL
+    ('\u{0236}', "t"),   // ȶ LATIN SMALL LETTER T WITH CURL
+    ('\u{0237}', "j"),   // ȷ LATIN SMALL LETTER DOTLESS J
+    ('\u{0238}', "db"),  // ȸ LATIN SMALL LETTER DB DIGRAPH
+    ('\u{0239}', "qp"),  // ȹ LATIN SMALL LETTER QP DIGRAPH
+    ('\u{023A}', "A"),   // Ⱥ LATIN CAPITAL LETTER A WITH STROKE
+    ('\u{023B}', "C"),   // Ȼ LATIN CAPITAL LETTER C WITH STROKE
+    ('\u{023C}', "c"),   // ȼ LATIN SMALL LETTER C WITH STROKE
+    ('\u{023D}', "L"),   // Ƚ LATIN CAPITAL LETTER L WITH BAR
+    ('\u{023E}', "T"),   // Ⱦ LATIN CAPITAL LETTER T WITH DIAGONAL STROKE
+    ('\u{023F}', "s"),   // ȿ LATIN SMALL LETTER S WITH SWASH TAIL
+    ('\u{0240}', "z"),   // ɀ LATIN SMALL LETTER Z WITH SWASH TAIL
+    ('\u{0243}', "B"),   // Ƀ LATIN CAPITAL LETTER B WITH STROKE
+    ('\u{0246}', "E"),   // Ɇ LATIN CAPITAL LETTER E WITH STROKE
+    ('\u{0247}', "e"),   // ɇ LATIN SMALL LETTER E WITH STROKE
+    ('\u{0248}', "J"),   // Ɉ LATIN CAPITAL LETTER J WITH STROKE
+    ('\u{0249}', "j"),   // ɉ LATIN SMALL LETTER J WITH STROKE
+    ('\u{024A}', "Q"),   // Ɋ LATIN CAPITAL LETTER SMALL Q WITH HOOK TAIL
+    ('\u{024B}', "q"),   // ɋ LATIN SMALL LETTER Q WITH HOOK TAIL
+    ('\u{024C}', "R"),   // Ɍ LATIN CAPITAL LETTER R WITH STROKE
+    ('\u{024D}', "r"),   // ɍ LATIN SMALL LETTER R WITH STROKE
+    ('\u{024E}', "Y"),   // Ɏ LATIN CAPITAL LETTER Y WITH STROKE
+    ('\u{024F}', "y"),   // ɏ LATIN SMALL LETTER Y WITH STROKE
+    ('\u{0253}', "b"),   // ɓ LATIN SMALL LETTER B WITH HOOK
+    ('\u{0255}', "c"),   // ɕ LATIN SMALL LETTER C WITH CURL
+    ('\u{0256}', "d"),   // ɖ LATIN SMALL LETTER D WITH TAIL
+    ('\u{0257}', "d"),   // ɗ LATIN SMALL LETTER D WITH HOOK
+    ('\u{025F}', "j"),   // ɟ LATIN SMALL LETTER DOTLESS J WITH STROKE
+    ('\u{0260}', "g"),   // ɠ LATIN SMALL LETTER G WITH HOOK
+    ('\u{0262}', "g"),   // ɢ LATIN LETTER SMALL CAPITAL G
+    ('\u{0266}', "h"),   // ɦ LATIN SMALL LETTER H WITH HOOK
+    ('\u{0268}', "i"),   // ɨ LATIN SMALL LETTER I WITH STROKE
+    ('\u{026A}', "i"),   // ɪ LATIN LETTER SMALL CAPITAL I
+    ('\u{026B}', "l"),   // ɫ LATIN SMALL LETTER L WITH MIDDLE TILDE
+    ('\u{026C}', "l"),   // ɬ LATIN SMALL LETTER L WITH BELT
+    ('\u{026D}', "l"),   // ɭ LATIN SMALL LETTER L WITH RETROFLEX HOOK
+    ('\u{0271}', "m"),   // ɱ LATIN SMALL LETTER M WITH HOOK
+    ('\u{0272}', "n"),   // ɲ LATIN SMALL LETTER N WITH LEFT HOOK
+    ('\u{0273}', "n"),   // ɳ LATIN SMALL LETTER N WITH RETROFLEX HOOK
+    ('\u{0274}', "n"),   // ɴ LATIN LETTER SMALL CAPITAL N
+    ('\u{0276}', "oe"),  // ɶ LATIN LETTER SMALL CAPITAL OE
+    ('\u{027D}', "r"),   // ɽ LATIN SMALL LETTER R WITH TAIL
+    ('\u{027E}', "r"),   // ɾ LATIN SMALL LETTER R WITH FISHHOOK
+    ('\u{0280}', "r"),   // ʀ LATIN LETTER SMALL CAPITAL R
+    ('\u{0282}', "s"),   // ʂ LATIN SMALL LETTER S WITH HOOK
+    ('\u{0284}', "j"),   // ʄ LATIN SMALL LETTER DOTLESS J WITH STROKE AND HOOK
+    ('\u{0288}', "t"),   // ʈ LATIN SMALL LETTER T WITH RETROFLEX HOOK
+    ('\u{028B}', "v"),   // ʋ LATIN SMALL LETTER V WITH HOOK
+    ('\u{028F}', "y"),   // ʏ LATIN LETTER SMALL CAPITAL Y
+    ('\u{0290}', "z"),   // ʐ LATIN SMALL LETTER Z WITH RETROFLEX HOOK
+    ('\u{0291}', "z"),   // ʑ LATIN SMALL LETTER Z WITH CURL
+    ('\u{0299}', "b"),   // ʙ LATIN LETTER SMALL CAPITAL B
+    ('\u{029B}', "g"),   // ʛ LATIN LETTER SMALL CAPITAL G WITH HOOK
+    ('\u{029C}', "h"),   // ʜ LATIN LETTER SMALL CAPITAL H
+    ('\u{029D}', "j"),   // ʝ LATIN SMALL LETTER J WITH CROSSED-TAIL
+    ('\u{029F}', "l"),   // ʟ LATIN LETTER SMALL CAPITAL L
+    ('\u{02A0}', "q"),   // ʠ LATIN SMALL LETTER Q WITH HOOK
+    ('\u{02A3}', "dz"),  // ʣ LATIN SMALL LETTER DZ DIGRAPH
+    ('\u{02A5}', "dz"),  // ʥ LATIN SMALL LETTER DZ DIGRAPH WITH CURL
+    ('\u{02A6}', "ts"),  // ʦ LATIN SMALL LETTER TS DIGRAPH
+    ('\u{02AA}', "ls"),  // ʪ LATIN SMALL LETTER LS DIGRAPH
+    ('\u{02AB}', "lz"),  // ʫ LATIN SMALL LETTER LZ DIGRAPH
+    ('\u{03D0}', "β"),   // ϐ GREEK BETA SYMBOL
+    ('\u{03D1}', "θ"),   // ϑ GREEK THETA SYMBOL
+    ('\u{03D5}', "φ"),   // ϕ GREEK PHI SYMBOL
+    ('\u{03D6}', "π"),   // ϖ GREEK PI SYMBOL
+    ('\u{03F0}', "κ"),   // ϰ GREEK KAPPA SYMBOL
+    ('\u{03F1}', "ρ"),   // ϱ GREEK RHO SYMBOL
+    ('\u{03F2}', "σ"),   // ϲ GREEK LUNATE SIGMA SYMBOL
+    ('\u{03F4}', "Θ"),   // ϴ GREEK CAPITAL THETA SYMBOL
+    ('\u{03F5}', "ε"),   // ϵ GREEK LUNATE EPSILON SYMBOL
+    ('\u{03F9}', "Σ"),   // Ϲ GREEK CAPITAL LUNATE SIGMA SYMBOL
+    ('\u{0490}', "Г"),   // Ґ CYRILLIC CAPITAL LETTER GHE WITH UPTURN
+    ('\u{0491}', "г"),   // ґ CYRILLIC SMALL LETTER GHE WITH UPTURN
+    ('\u{0492}', "Г"),   // Ғ CYRILLIC CAPITAL LETTER GHE WITH STROKE
+    ('\u{0493}', "г"),   // ғ CYRILLIC SMALL LETTER GHE WITH STROKE
+    ('\u{0494}', "Г"),   // Ҕ CYRILLIC CAPITAL LETTER GHE WITH MIDDLE HOOK
+    ('\u{0495}', "г"),   // ҕ CYRILLIC SMALL LETTER GHE WITH MIDDLE HOOK
+    ('\u{049A}', "К"),   // Қ CYRILLIC CAPITAL LETTER KA WITH DESCENDER
+    ('\u{049B}', "к"),   // қ CYRILLIC SMALL LETTER KA WITH DESCENDER
+    ('\u{049C}', "К"),   // Ҝ CYRILLIC CAPITAL LETTER KA WITH VERTICAL STROKE
+    ('\u{049D}', "к"),   // ҝ CYRILLIC SMALL LETTER KA WITH VERTICAL STROKE
+    ('\u{049E}', "К"),   // Ҟ CYRILLIC CAPITAL LETTER KA WITH STROKE
+    ('\u{049F}', "к"),   // ҟ CYRILLIC SMALL LETTER KA WITH STROKE
+    ('\u{04A0}', "К"),   // Ҡ CYRILLIC CAPITAL LETTER BASHKIR KA
+    ('\u{04A1}', "к"),   // ҡ CYRILLIC SMALL LETTER BASHKIR KA
+    ('\u{04A2}', "Н"),   // Ң CYRILLIC CAPITAL LETTER EN WITH DESCENDER
+    ('\u{04A3}', "н"),   // ң CYRILLIC SMALL LETTER EN WITH DESCENDER
+    ('\u{04A4}', "Н"),   // Ҥ CYRILLIC CAPITAL LIGATURE EN GHE
+    ('\u{04A5}', "н"),   // ҥ CYRILLIC SMALL LIGATURE EN GHE
+    ('\u{04A6}', "П"),   // Ҧ CYRILLIC CAPITAL LETTER PE WITH MIDDLE HOOK
+    ('\u{04A7}', "п"),   // ҧ CYRILLIC SMALL LETTER PE WITH MIDDLE HOOK
+    ('\u{04AA}', "С"),   // Ҫ CYRILLIC CAPITAL LETTER ES WITH DESCENDER
+    ('\u{04AB}', "с"),   // ҫ CYRILLIC SMALL LETTER ES WITH DESCENDER
+    ('\u{04AC}', "Т"),   // Ҭ CYRILLIC CAPITAL LETTER TE WITH DESCENDER
+    ('\u{04AD}', "т"),   // ҭ CYRILLIC SMALL LETTER TE WITH DESCENDER
+    ('\u{04AE}', "У"),   // Ү CYRILLIC CAPITAL LETTER STRAIGHT U
+    ('\u{04AF}', "у"),   // ү CYRILLIC SMALL LETTER STRAIGHT U
+    ('\u{04B0}', "У"),   // Ұ CYRILLIC CAPITAL LETTER STRAIGHT U WITH STROKE
+    ('\u{04B1}', "у"),   // ұ CYRILLIC SMALL LETTER STRAIGHT U WITH STROKE
+    ('\u{04B2}', "Х"),   // Ҳ CYRILLIC CAPITAL LETTER HA WITH DESCENDER
+    ('\u{04B3}', "х"),   // ҳ CYRILLIC SMALL LETTER HA WITH DESCENDER
+    ('\u{04B4}', "Ц"),   // Ҵ CYRILLIC CAPITAL LIGATURE TE TSE
+    ('\u{04B5}', "ц"),   // ҵ CYRILLIC SMALL LIGATURE TE TSE
+    ('\u{04B6}', "Ч"),   // Ҷ CYRILLIC CAPITAL LETTER CHE WITH DESCENDER
+    ('\u{04B7}', "ч"),   // ҷ CYRILLIC SMALL LETTER CHE WITH DESCENDER
+    ('\u{04B8}', "Ч"),   // Ҹ CYRILLIC CAPITAL LETTER CHE WITH VERTICAL STROKE
+    ('\u{04B9}', "ч"),   // ҹ CYRILLIC SMALL LETTER CHE WITH VERTICAL STROKE
+    ('\u{04C3}', "К"),   // Ӄ CYRILLIC CAPITAL LETTER KA WITH HOOK
+    ('\u{04C4}', "к"),   // ӄ CYRILLIC SMALL LETTER KA WITH HOOK
+    ('\u{04C5}', "Л"),   // Ӆ CYRILLIC CAPITAL LETTER EL WITH TAIL
+    ('\u{04C6}', "л"),   // ӆ CYRILLIC SMALL LETTER EL WITH TAIL
+    ('\u{04C7}', "Н"),   // Ӈ CYRILLIC CAPITAL LETTER EN WITH HOOK
+    ('\u{04C8}', "н"),   // ӈ CYRILLIC SMALL LETTER EN WITH HOOK
+    ('\u{04C9}', "Н"),   // Ӊ CYRILLIC CAPITAL LETTER EN WITH TAIL
+    ('\u{04CA}', "н"),   // ӊ CYRILLIC SMALL LETTER EN WITH TAIL
+    ('\u{04CB}', "Ч"),   // Ӌ CYRILLIC CAPITAL LETTER KHAKASSIAN CHE
+    ('\u{04CC}', "ч"),   // ӌ CYRILLIC SMALL LETTER KHAKASSIAN CHE
+    ('\u{04CD}', "М"),   // Ӎ CYRILLIC CAPITAL LETTER EM WITH TAIL
+    ('\u{04CE}', "м"),   // ӎ CYRILLIC SMALL LETTER EM WITH TAIL
+    ('\u{04F6}', "Г"),   // Ӷ CYRILLIC CAPITAL LETTER GHE WITH DESCENDER
+    ('\u{04F7}', "г"),   // ӷ CYRILLIC SMALL LETTER GHE WITH DESCENDER
+    ('\u{04FA}', "Г"),   // Ӻ CYRILLIC CAPITAL LETTER GHE WITH STROKE AND HOOK
+    ('\u{04FB}', "г"),   // ӻ CYRILLIC SMALL LETTER GHE WITH STROKE AND HOOK
+    ('\u{04FC}', "Х"),   // Ӽ CYRILLIC CAPITAL LETTER HA WITH HOOK
+    ('\u{04FD}', "х"),   // ӽ CYRILLIC SMALL LETTER HA WITH HOOK
+    ('\u{04FE}', "Х"),   // Ӿ CYRILLIC CAPITAL LETTER HA WITH STROKE
+    ('\u{04FF}', "х"),   // ӿ CYRILLIC SMALL LETTER HA WITH STROKE
+    ('\u{0512}', "Л"),   // Ԓ CYRILLIC CAPITAL LETTER EL WITH HOOK
+    ('\u{0513}', "л"),   // ԓ CYRILLIC SMALL LETTER EL WITH HOOK
+    ('\u{0520}', "Л"),   // Ԡ CYRILLIC CAPITAL LETTER EL WITH MIDDLE HOOK
+    ('\u{0521}', "л"),   // ԡ CYRILLIC SMALL LETTER EL WITH MIDDLE HOOK
+    ('\u{0522}', "Н"),   // Ԣ CYRILLIC CAPITAL LETTER EN WITH MIDDLE HOOK
+    ('\u{0523}', "н"),   // ԣ CYRILLIC SMALL LETTER EN WITH MIDDLE HOOK
+    ('\u{0528}', "Н"),   // Ԩ CYRILLIC CAPITAL LETTER EN WITH LEFT HOOK
+    ('\u{0529}', "н"),   // ԩ CYRILLIC SMALL LETTER EN WITH LEFT HOOK
+    ('\u{052E}', "Л"),   // Ԯ CYRILLIC CAPITAL LETTER EL WITH DESCENDER
+    ('\u{052F}', "л"),   // ԯ CYRILLIC SMALL LETTER EL WITH DESCENDER
+    ('\u{1D00}', "a"),   // ᴀ LATIN LETTER SMALL CAPITAL A
+    ('\u{1D01}', "ae"),  // ᴁ LATIN LETTER SMALL CAPITAL AE
+    ('\u{1D04}', "c"),   // ᴄ LATIN LETTER SMALL CAPITAL C
+    ('\u{1D05}', "d"),   // ᴅ LATIN LETTER SMALL CAPITAL D
+    ('\u{1D06}', "d"),   // ᴆ LATIN LETTER SMALL CAPITAL ETH
+    ('\u{1D07}', "e"),   // ᴇ LATIN LETTER SMALL CAPITAL E
+    ('\u{1D0A}', "j"),   // ᴊ LATIN LETTER SMALL CAPITAL J
+    ('\u{1D0B}', "k"),   // ᴋ LATIN LETTER SMALL CAPITAL K
+    ('\u{1D0C}', "l"),   // ᴌ LATIN LETTER SMALL CAPITAL L WITH STROKE
+    ('\u{1D0D}', "m"),   // ᴍ LATIN LETTER SMALL CAPITAL M
+    ('\u{1D0F}', "o"),   // ᴏ LATIN LETTER SMALL CAPITAL O
+    ('\u{1D15}', "ou"),  // ᴕ LATIN LETTER SMALL CAPITAL OU
+    ('\u{1D18}', "p"),   // ᴘ LATIN LETTER SMALL CAPITAL P
+    ('\u{1D1B}', "t"),   // ᴛ LATIN LETTER SMALL CAPITAL T
+    ('\u{1D1C}', "u"),   // ᴜ LATIN LETTER SMALL CAPITAL U
+    ('\u{1D20}', "v"),   // ᴠ LATIN LETTER SMALL CAPITAL V
+    ('\u{1D21}', "w"),   // ᴡ LATIN LETTER SMALL CAPITAL W
+    ('\u{1D22}', "z"),   // ᴢ LATIN LETTER SMALL CAPITAL Z
+    ('\u{1D6B}', "ue"),  // ᵫ LATIN SMALL LETTER UE
+    ('\u{1D6C}', "b"),   // ᵬ LATIN SMALL LETTER B WITH MIDDLE TILDE
+    ('\u{1D6D}', "d"),   // ᵭ LATIN SMALL LETTER D WITH MIDDLE TILDE
+    ('\u{1D6E}', "f"),   // ᵮ LATIN SMALL LETTER F WITH MIDDLE TILDE
+    ('\u{1D6F}', "m"),   // ᵯ LATIN SMALL LETTER M WITH MIDDLE TILDE
+    ('\u{1D70}', "n"),   // ᵰ LATIN SMALL LETTER N WITH MIDDLE TILDE
+    ('\u{1D71}', "p"),   // ᵱ LATIN SMALL LETTER P WITH MIDDLE TILDE
+    ('\u{1D72}', "r"),   // ᵲ LATIN SMALL LETTER R WITH MIDDLE TILDE
+    ('\u{1D74}', "s"),   // ᵴ LATIN SMALL LETTER S WITH MIDDLE TILDE
+    ('\u{1D75}', "t"),   // ᵵ LATIN SMALL LETTER T WITH MIDDLE TILDE
+    ('\u{1D76}', "z"),   // ᵶ LATIN SMALL LETTER Z WITH MIDDLE TILDE
+    ('\u{1D7D}', "p"),   // ᵽ LATIN SMALL LETTER P WITH STROKE
+    ('\u{1D80}', "b"),   // ᶀ LATIN SMALL LETTER B WITH PALATAL HOOK
+    ('\u{1D81}', "d"),   // ᶁ LATIN SMALL LETTER D WITH PALATAL HOOK
+    ('\u{1D82}', "f"),   // ᶂ LATIN SMALL LETTER F WITH PALATAL HOOK
+    ('\u{1D83}', "g"),   // ᶃ LATIN SMALL LETTER G WITH PALATAL HOOK
+    ('\u{1D84}', "k"),   // ᶄ LATIN SMALL LETTER K WITH PALATAL HOOK
+    ('\u{1D85}', "l"),   // ᶅ LATIN SMALL LETTER L WITH PALATAL HOOK
+    ('\u{1D86}', "m"),   // ᶆ LATIN SMALL LETTER M WITH PALATAL HOOK
+    ('\u{1D87}', "n"),   // ᶇ LATIN SMALL LETTER N WITH PALATAL HOOK
+    ('\u{1D88}', "p"),   // ᶈ LATIN SMALL LETTER P WITH PALATAL HOOK
+    ('\u{1D89}', "r"),   // ᶉ LATIN SMALL LETTER R WITH PALATAL HOOK
+    ('\u{1D8A}', "s"),   // ᶊ LATIN SMALL LETTER S WITH PALATAL HOOK
+    ('\u{1D8C}', "v"),   // ᶌ LATIN SMALL LETTER V WITH PALATAL HOOK
+    ('\u{1D8D}', "x"),   // ᶍ LATIN SMALL LETTER X WITH PALATAL HOOK
+    ('\u{1D8E}', "z"),   // ᶎ LATIN SMALL LETTER Z WITH PALATAL HOOK
+    ('\u{1D8F}', "a"),   // ᶏ LATIN SMALL LETTER A WITH RETROFLEX HOOK
+    ('\u{1D91}', "d"),   // ᶑ LATIN SMALL LETTER D WITH HOOK AND TAIL
+    ('\u{1D92}', "e"),   // ᶒ LATIN SMALL LETTER E WITH RETROFLEX HOOK
+    ('\u{1D96}', "i"),   // ᶖ LATIN SMALL LETTER I WITH RETROFLEX HOOK
+    ('\u{1D99}', "u"),   // ᶙ LATIN SMALL LETTER U WITH RETROFLEX HOOK
+    ('\u{1E9B}', "s"),   // ẛ LATIN SMALL LETTER LONG S WITH DOT ABOVE
+    ('\u{1E9C}', "s"),   // ẜ LATIN SMALL LETTER LONG S WITH DIAGONAL STROKE
+    ('\u{1E9D}', "s"),   // ẝ LATIN SMALL LETTER LONG S WITH HIGH STROKE
+    ('\u{1E9E}', "SS"),  // ẞ LATIN CAPITAL LETTER SHARP S
+    ('\u{1EFE}', "Y"),   // Ỿ LATIN CAPITAL LETTER Y WITH LOOP
+    ('\u{1EFF}', "y"),   // ỿ LATIN SMALL LETTER Y WITH LOOP
+    ('\u{2C60}', "L"),   // Ⱡ LATIN CAPITAL LETTER L WITH DOUBLE BAR
+    ('\u{2C61}', "l"),   // ⱡ LATIN SMALL LETTER L WITH DOUBLE BAR
+    ('\u{2C62}', "L"),   // Ɫ LATIN CAPITAL LETTER L WITH MIDDLE TILDE
+    ('\u{2C63}', "P"),   // Ᵽ LATIN CAPITAL LETTER P WITH STROKE
+    ('\u{2C64}', "R"),   // Ɽ LATIN CAPITAL LETTER R WITH TAIL
+    ('\u{2C65}', "a"),   // ⱥ LATIN SMALL LETTER A WITH STROKE
+    ('\u{2C66}', "t"),   // ⱦ LATIN SMALL LETTER T WITH DIAGONAL STROKE
+    ('\u{2C67}', "H"),   // Ⱨ LATIN CAPITAL LETTER H WITH DESCENDER
+    ('\u{2C68}', "h"),   // ⱨ LATIN SMALL LETTER H WITH DESCENDER
+    ('\u{2C69}', "K"),   // Ⱪ LATIN CAPITAL LETTER K WITH DESCENDER
+    ('\u{2C6A}', "k"),   // ⱪ LATIN SMALL LETTER K WITH DESCENDER
+    ('\u{2C6B}', "Z"),   // Ⱬ LATIN CAPITAL LETTER Z WITH DESCENDER
+    ('\u{2C6C}', "z"),   // ⱬ LATIN SMALL LETTER Z WITH DESCENDER
+    ('\u{2C6E}', "M"),   // Ɱ LATIN CAPITAL LETTER M WITH HOOK
+    ('\u{2C71}', "v"),   // ⱱ LATIN SMALL LETTER V WITH RIGHT HOOK
+    ('\u{2C72}', "W"),   // Ⱳ LATIN CAPITAL LETTER W WITH HOOK
+    ('\u{2C73}', "w"),   // ⱳ LATIN SMALL LETTER W WITH HOOK
+    ('\u{2C74}', "v"),   // ⱴ LATIN SMALL LETTER V WITH CURL
+    ('\u{2C78}', "e"),   // ⱸ LATIN SMALL LETTER E WITH NOTCH
+    ('\u{2C7A}', "o"),   // ⱺ LATIN SMALL LETTER O WITH LOW RING INSIDE
+    ('\u{2C7E}', "S"),   // Ȿ LATIN CAPITAL LETTER S WITH SWASH TAIL
+    ('\u{2C7F}', "Z"),   // Ɀ LATIN CAPITAL LETTER Z WITH SWASH TAIL
+    ('\u{A728}', "TZ"),  // Ꜩ LATIN CAPITAL LETTER TZ
+    ('\u{A729}', "tz"),  // ꜩ LATIN SMALL LETTER TZ
+    ('\u{A730}', "f"),   // ꜰ LATIN LETTER SMALL CAPITAL F
+    ('\u{A731}', "s"),   // ꜱ LATIN LETTER SMALL CAPITAL S
+    ('\u{A732}', "AA"),  // Ꜳ LATIN CAPITAL LETTER AA
+    ('\u{A733}', "aa"),  // ꜳ LATIN SMALL LETTER AA
+    ('\u{A734}', "AO"),  // Ꜵ LATIN CAPITAL LETTER AO
+    ('\u{A735}', "ao"),  // ꜵ LATIN SMALL LETTER AO
+    ('\u{A736}', "AU"),  // Ꜷ LATIN CAPITAL LETTER AU
+    ('\u{A737}', "au"),  // ꜷ LATIN SMALL LETTER AU
+    ('\u{A738}', "AV"),  // Ꜹ LATIN CAPITAL LETTER AV
+    ('\u{A739}', "av"),  // ꜹ LATIN SMALL LETTER AV
+    ('\u{A73A}', "AV"),  // Ꜻ LATIN CAPITAL LETTER AV WITH HORIZONTAL BAR
+    ('\u{A73B}', "av"),  // ꜻ LATIN SMALL LETTER AV WITH HORIZONTAL BAR
+    ('\u{A73C}', "AY"),  // Ꜽ LATIN CAPITAL LETTER AY
+    ('\u{A73D}', "ay"),  // ꜽ LATIN SMALL LETTER AY
+    ('\u{A740}', "K"),   // Ꝁ LATIN CAPITAL LETTER K WITH STROKE
+    ('\u{A741}', "k"),   // ꝁ LATIN SMALL LETTER K WITH STROKE
+    ('\u{A742}', "K"),   // Ꝃ LATIN CAPITAL LETTER K WITH DIAGONAL STROKE
+    ('\u{A743}', "k"),   // ꝃ LATIN SMALL LETTER K WITH DIAGONAL STROKE
+    ('\u{A744}', "K"),   // Ꝅ LATIN CAPITAL LETTER K WITH STROKE AND DIAGONAL STROKE
+    ('\u{A745}', "k"),   // ꝅ LATIN SMALL LETTER K WITH STROKE AND DIAGONAL STROKE
+    ('\u{A748}', "L"),   // Ꝉ LATIN CAPITAL LETTER L WITH HIGH STROKE
+    ('\u{A749}', "l"),   // ꝉ LATIN SMALL LETTER L WITH HIGH STROKE
+    ('\u{A74A}', "O"),   // Ꝋ LATIN CAPITAL LETTER O WITH LONG STROKE OVERLAY
+    ('\u{A74B}', "o"),   // ꝋ LATIN SMALL LETTER O WITH LONG STROKE OVERLAY
+    ('\u{A74C}', "O"),   // Ꝍ LATIN CAPITAL LETTER O WITH LOOP
+    ('\u{A74D}', "o"),   // ꝍ LATIN SMALL LETTER O WITH LOOP
+    ('\u{A74E}', "OO"),  // Ꝏ LATIN CAPITAL LETTER OO
+    ('\u{A74F}', "oo"),  // ꝏ LATIN SMALL LETTER OO
+    ('\u{A750}', "P"),   // Ꝑ LATIN CAPITAL LETTER P WITH STROKE THROUGH DESCENDER
+    ('\u{A751}', "p"),   // ꝑ LATIN SMALL LETTER P WITH STROKE THROUGH DESCENDER
+    ('\u{A752}', "P"),   // Ꝓ LATIN CAPITAL LETTER P WITH FLOURISH
+    ('\u{A753}', "p"),   // ꝓ LATIN SMALL LETTER P WITH FLOURISH
+    ('\u{A754}', "P"),   // Ꝕ LATIN CAPITAL LETTER P WITH SQUIRREL TAIL
+    ('\u{A755}', "p"),   // ꝕ LATIN SMALL LETTER P WITH SQUIRREL TAIL
+    ('\u{A756}', "Q"),   // Ꝗ LATIN CAPITAL LETTER Q WITH STROKE THROUGH DESCENDER
+    ('\u{A757}', "q"),   // ꝗ LATIN SMALL LETTER Q WITH STROKE THROUGH DESCENDER
+    ('\u{A758}', "Q"),   // Ꝙ LATIN CAPITAL LETTER Q WITH DIAGONAL STROKE
+    ('\u{A759}', "q"),   // ꝙ LATIN SMALL LETTER Q WITH DIAGONAL STROKE
+    ('\u{A75E}', "V"),   // Ꝟ LATIN CAPITAL LETTER V WITH DIAGONAL STROKE
+    ('\u{A75F}', "v"),   // ꝟ LATIN SMALL LETTER V WITH DIAGONAL STROKE
+    ('\u{A760}', "VY"),  // Ꝡ LATIN CAPITAL LETTER VY
+    ('\u{A761}', "vy"),  // ꝡ LATIN SMALL LETTER VY
+    ('\u{A76A}', "ET"),  // Ꝫ LATIN CAPITAL LETTER ET
+    ('\u{A76B}', "et"),  // ꝫ LATIN SMALL LETTER ET
+    ('\u{A76C}', "IS"),  // Ꝭ LATIN CAPITAL LETTER IS
+    ('\u{A76D}', "is"),  // ꝭ LATIN SMALL LETTER IS
+    ('\u{A76E}', "CON"), // Ꝯ LATIN CAPITAL LETTER CON
+    ('\u{A76F}', "con"), // ꝯ LATIN SMALL LETTER CON
+    ('\u{A778}', "um"),  // ꝸ LATIN SMALL LETTER UM
+    ('\u{A790}', "N"),   // Ꞑ LATIN CAPITAL LETTER N WITH DESCENDER
+    ('\u{A791}', "n"),   // ꞑ LATIN SMALL LETTER N WITH DESCENDER
+    ('\u{A792}', "C"),   // Ꞓ LATIN CAPITAL LETTER C WITH BAR
+    ('\u{A793}', "c"),   // ꞓ LATIN SMALL LETTER C WITH BAR
+    ('\u{A794}', "c"),   // ꞔ LATIN SMALL LETTER C WITH PALATAL HOOK
+    ('\u{A795}', "h"),   // ꞕ LATIN SMALL LETTER H WITH PALATAL HOOK
+    ('\u{A796}', "B"),   // Ꞗ LATIN CAPITAL LETTER B WITH FLOURISH
+    ('\u{A797}', "b"),   // ꞗ LATIN SMALL LETTER B WITH FLOURISH
+    ('\u{A798}', "F"),   // Ꞙ LATIN CAPITAL LETTER F WITH STROKE
+    ('\u{A799}', "f"),   // ꞙ LATIN SMALL LETTER F WITH STROKE
+    ('\u{A7A0}', "G"),   // Ꞡ LATIN CAPITAL LETTER G WITH OBLIQUE STROKE
+    ('\u{A7A1}', "g"),   // ꞡ LATIN SMALL LETTER G WITH OBLIQUE STROKE
+    ('\u{A7A2}', "K"),   // Ꞣ LATIN CAPITAL LETTER K WITH OBLIQUE STROKE
+    ('\u{A7A3}', "k"),   // ꞣ LATIN SMALL LETTER K WITH OBLIQUE STROKE
+    ('\u{A7A4}', "N"),   // Ꞥ LATIN CAPITAL LETTER N WITH OBLIQUE STROKE
+    ('\u{A7A5}', "n"),   // ꞥ LATIN SMALL LETTER N WITH OBLIQUE STROKE
+    ('\u{A7A6}', "R"),   // Ꞧ LATIN CAPITAL LETTER R WITH OBLIQUE STROKE
+    ('\u{A7A7}', "r"),   // ꞧ LATIN SMALL LETTER R WITH OBLIQUE STROKE
+    ('\u{A7A8}', "S"),   // Ꞩ LATIN CAPITAL LETTER S WITH OBLIQUE STROKE
+    ('\u{A7A9}', "s"),   // ꞩ LATIN SMALL LETTER S WITH OBLIQUE STROKE
+    ('\u{A7AA}', "H"),   // Ɦ LATIN CAPITAL LETTER H WITH HOOK
+    ('\u{A7AD}', "L"),   // Ɬ LATIN CAPITAL LETTER L WITH BELT
+    ('\u{A7AE}', "I"),   // Ɪ LATIN CAPITAL LETTER SMALL CAPITAL I
+    ('\u{A7AF}', "q"),   // ꞯ LATIN LETTER SMALL CAPITAL Q
+    ('\u{A7B2}', "J"),   // Ʝ LATIN CAPITAL LETTER J WITH CROSSED-TAIL
+    ('\u{A7B8}', "U"),   // Ꞹ LATIN CAPITAL LETTER U WITH STROKE
+    ('\u{A7B9}', "u"),   // ꞹ LATIN SMALL LETTER U WITH STROKE
+    ('\u{A7C4}', "C"),   // Ꞔ LATIN CAPITAL LETTER C WITH PALATAL HOOK
+    ('\u{A7C5}', "S"),   // Ʂ LATIN CAPITAL LETTER S WITH HOOK
+    ('\u{A7C6}', "Z"),   // Ᶎ LATIN CAPITAL LETTER Z WITH PALATAL HOOK
+    ('\u{A7C7}', "D"),   // Ꟈ LATIN CAPITAL LETTER D WITH SHORT STROKE OVERLAY
+    ('\u{A7C8}', "d"),   // ꟈ LATIN SMALL LETTER D WITH SHORT STROKE OVERLAY
+    ('\u{A7C9}', "S"),   // Ꟊ LATIN CAPITAL LETTER S WITH SHORT STROKE OVERLAY
+    ('\u{A7CA}', "s"),   // ꟊ LATIN SMALL LETTER S WITH SHORT STROKE OVERLAY
+    ('\u{AB34}', "e"),   // ꬴ LATIN SMALL LETTER E WITH FLOURISH
+    ('\u{AB38}', "l"),   // ꬸ LATIN SMALL LETTER L WITH DOUBLE MIDDLE TILDE
+    ('\u{AB39}', "l"),   // ꬹ LATIN SMALL LETTER L WITH MIDDLE RING
+    ('\u{AB3A}', "m"),   // ꬺ LATIN SMALL LETTER M WITH CROSSED-TAIL
+    ('\u{AB3B}', "n"),   // ꬻ LATIN SMALL LETTER N WITH CROSSED-TAIL
+    ('\u{AB3C}', "n"),   // ꬼ LATIN SMALL LETTER ENG WITH CROSSED-TAIL
+    ('\u{AB46}', "r"),   // ꭆ LATIN LETTER SMALL CAPITAL R WITH RIGHT LEG
+    ('\u{AB49}', "r"),   // ꭉ LATIN SMALL LETTER R WITH CROSSED-TAIL
+    ('\u{AB4E}', "u"),   // ꭎ LATIN SMALL LETTER U WITH SHORT RIGHT LEG
+    ('\u{AB52}', "u"),   // ꭒ LATIN SMALL LETTER U WITH LEFT HOOK
+    ('\u{AB56}', "x"),   // ꭖ LATIN SMALL LETTER X WITH LOW RIGHT RING
+    ('\u{AB57}', "x"),   // ꭗ LATIN SMALL LETTER X WITH LONG LEFT LEG
+    ('\u{AB58}', "x"),   // ꭘ LATIN SMALL LETTER X WITH LONG LEFT LEG AND LOW RIGHT RING
+    ('\u{AB59}', "x"),   // ꭙ LATIN SMALL LETTER X WITH LONG LEFT LEG WITH SERIF
+    ('\u{AB5A}', "y"),   // ꭚ LATIN SMALL LETTER Y WITH SHORT RIGHT LEG
+    ('\u{AB66}', "dz"),  // ꭦ LATIN SMALL LETTER DZ DIGRAPH WITH RETROFLEX HOOK
+    ('\u{FB00}', "ff"),  // ﬀ LATIN SMALL LIGATURE FF
+    ('\u{FB01}', "fi"),  // ﬁ LATIN SMALL LIGATURE FI
+    ('\u{FB02}', "fl"),  // ﬂ LATIN SMALL LIGATURE FL
+    ('\u{FB03}', "ffi"), // ﬃ LATIN SMALL LIGATURE FFI
+    ('\u{FB04}', "ffl"), // ﬄ LATIN SMALL LIGATURE FFL
+    ('\u{FB05}', "st"),  // ﬅ LATIN SMALL LIGATURE LONG S T
+    ('\u{FB06}', "st"),  // ﬆ LATIN SMALL LIGATURE ST
+];
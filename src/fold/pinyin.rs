@@ -0,0 +1,432 @@
+//! Pinyin romanization of Chinese text.
+//!
+//! Lets `北京` be found by typing `beijing`, `běijīng` or `bj`: Han characters are replaced
+//! by their Mandarin reading, everything else passes through unchanged.
+//!
+//! Many characters have more than one reading (行 xíng/háng, 长 cháng/zhǎng, 乐 lè/yuè).
+//! Each character has a default reading, and a phrase dictionary overrides it where a
+//! known word matches (银行 yín háng, 长大 zhǎng dà, 音乐 yīn yuè); phrases are found with
+//! leftmost-longest matching, as in [`super::chinese_ts`]. Traditional characters are looked
+//! up through the bundled Traditional → Simplified table, so 銀行 reads like 银行.
+//!
+//! The bundled dictionary covers roughly the 2,200 most frequent characters and the
+//! common polyphonic phrases. For full coverage, load the `pinyin-data` /
+//! `phrase-pinyin-data` dictionaries with [`PinyinDict::from_tables`].
+
+use super::chinese_ts;
+use crate::offset::{OffsetCursor, OffsetUnit};
+use crate::tokenize::{Token, TokenKind};
+use aho_corasick::{AhoCorasick, MatchKind};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use unicode_normalization::UnicodeNormalization;
+
+static CHARS: &str = include_str!("data/pinyin_chars.txt");
+static PHRASES: &str = include_str!("data/pinyin_phrases.txt");
+
+/// How each syllable is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PinyinStyle {
+    /// `beijing`. ü is written `v` (`lv`), as typed on a keyboard.
+    #[default]
+    Toneless,
+    /// `běijīng`.
+    ToneMarks,
+    /// `bei3jing1`, with `5` for the neutral tone and `v` for ü.
+    ToneNumbers,
+    /// First letter of each syllable: `bj`. `zh`/`ch`/`sh` give `z`/`c`/`s`.
+    Initials,
+}
+
+/// A character + phrase pinyin dictionary.
+#[derive(Debug, Clone)]
+pub struct PinyinDict {
+    /// Readings in numbered form (`zhong1`); the first one is the default.
+    chars: HashMap<char, Vec<String>>,
+    phrase_readings: Vec<Vec<String>>,
+    matcher: Option<AhoCorasick>,
+}
+
+impl PinyinDict {
+    /// Build a dictionary from character and phrase tables.
+    ///
+    /// One entry per line: a key, then its readings. Both the bundled format
+    /// (`中<TAB>zhong1 zhong4`, `银行<TAB>yin2 hang2`) and the `pinyin-data` /
+    /// `phrase-pinyin-data` formats (`U+4E2D: zhōng,zhòng  # 中`, `银行: yín háng`) are
+    /// accepted; readings may use tone marks or tone numbers. In the character table
+    /// the first reading is the default. Blank lines, `#` comments, and phrases whose
+    /// reading count does not match their length are ignored.
+    pub fn from_tables(chars: &str, phrases: &str) -> Self {
+        let mut char_map: HashMap<char, Vec<String>> = HashMap::new();
+        for (key, readings) in parse_table(chars) {
+            let mut kc = key.chars();
+            let (Some(c), None) = (kc.next(), kc.next()) else {
+                continue;
+            };
+            let entry = char_map.entry(c).or_default();
+            for r in readings {
+                if !entry.contains(&r) {
+                    entry.push(r);
+                }
+            }
+        }
+
+        let mut phrase_keys = Vec::new();
+        let mut phrase_readings = Vec::new();
+        for (key, readings) in parse_table(phrases) {
+            if key.chars().count() == readings.len() && !readings.is_empty() {
+                phrase_keys.push(key);
+                phrase_readings.push(readings);
+            }
+        }
+        let matcher = if phrase_keys.is_empty() {
+            None
+        } else {
+            Some(
+                AhoCorasick::builder()
+                    .match_kind(MatchKind::LeftmostLongest)
+                    .build(&phrase_keys)
+                    .expect("failed to build Aho-Corasick matcher"),
+            )
+        };
+        Self {
+            chars: char_map,
+            phrase_readings,
+            matcher,
+        }
+    }
+
+    /// All known readings of `c` in numbered form (`["xing2", "hang2"]`), default first.
+    pub fn readings(&self, c: char) -> Option<&[String]> {
+        self.lookup(c).map(Vec::as_slice)
+    }
+
+    fn lookup(&self, c: char) -> Option<&Vec<String>> {
+        self.chars.get(&c).or_else(|| {
+            let simplified = chinese_ts::traditional_to_simplified().convert_char(c);
+            (simplified != c)
+                .then(|| self.chars.get(&simplified))
+                .flatten()
+        })
+    }
+
+    /// Romanize `text`: each Han character with a known reading becomes a syllable in
+    /// `style`, with no separator; everything else is copied unchanged.
+    ///
+    /// `"北京 2008"` → `"beijing 2008"` ([`PinyinStyle::Toneless`]).
+    pub fn to_pinyin(&self, text: &str, style: PinyinStyle) -> String {
+        let mut out = String::with_capacity(text.len() * 2);
+        self.to_pinyin_into(text, style, &mut out);
+        out
+    }
+
+    /// Like [`PinyinDict::to_pinyin`], but writes into an existing `String`.
+    pub fn to_pinyin_into(&self, text: &str, style: PinyinStyle, out: &mut String) {
+        out.clear();
+        out.reserve(text.len() * 2);
+        self.walk(text, |start, end, reading| match reading {
+            Some(r) => render(r, style, out),
+            None => out.push_str(&text[start..end]),
+        });
+    }
+
+    /// The syllables of `text`, one per Han character with a known reading.
+    pub fn syllables(&self, text: &str, style: PinyinStyle) -> Vec<String> {
+        let mut out = Vec::new();
+        self.walk(text, |_, _, reading| {
+            if let Some(r) = reading {
+                let mut s = String::new();
+                render(r, style, &mut s);
+                out.push(s);
+            }
+        });
+        out
+    }
+
+    /// The syllables of `text` with the **char** offsets of the character each one
+    /// was read from.
+    ///
+    /// Offsets refer to `text` itself, so running this on the same input as
+    /// [`crate::scrub_with`] lets a pinyin match be highlighted in the original string.
+    pub fn syllables_with_offsets(&self, text: &str, style: PinyinStyle) -> Vec<Token> {
+        self.syllables_with_offsets_in(text, style, OffsetUnit::Char)
+    }
+
+    /// Like [`PinyinDict::syllables_with_offsets`], in the requested [`OffsetUnit`].
+    pub fn syllables_with_offsets_in(
+        &self,
+        text: &str,
+        style: PinyinStyle,
+        unit: OffsetUnit,
+    ) -> Vec<Token> {
+        let mut cursor = OffsetCursor::new(text, unit);
+        let mut out = Vec::new();
+        self.walk(text, |start, end, reading| {
+            if let Some(r) = reading {
+                let mut s = String::new();
+                render(r, style, &mut s);
+                out.push(Token {
+                    text: s,
+                    start: cursor.advance_to(start),
+                    end: cursor.advance_to(end),
+                    kind: TokenKind::Word,
+                });
+            }
+        });
+        out
+    }
+
+    /// Call `f(byte_start, byte_end, reading)` for every char of `text`, in order.
+    fn walk<'s>(&'s self, text: &str, mut f: impl FnMut(usize, usize, Option<&'s str>)) {
+        // Phrases are matched on a Simplified shadow of the text so that Traditional
+        // input hits the same entries. Char-table conversion is one-to-one, so shadow
+        // chars line up with source chars.
+        let ts = chinese_ts::traditional_to_simplified();
+        let mut shadow = String::with_capacity(text.len());
+        let mut shadow_starts = Vec::new();
+        let mut starts = Vec::new();
+        for (i, c) in text.char_indices() {
+            shadow_starts.push(shadow.len());
+            starts.push(i);
+            shadow.push(ts.convert_char(c));
+        }
+        shadow_starts.push(shadow.len());
+        starts.push(text.len());
+        let char_at = |k: usize| text[starts[k]..].chars().next().unwrap_or_default();
+        let index_of = |b: usize| shadow_starts.binary_search(&b).unwrap_or_default();
+
+        let mut next = 0;
+        if let Some(matcher) = &self.matcher {
+            for mat in matcher.find_iter(&shadow) {
+                let (s, e) = (index_of(mat.start()), index_of(mat.end()));
+                for k in next..s {
+                    let reading = self.lookup(char_at(k)).map(|r| r[0].as_str());
+                    f(starts[k], starts[k + 1], reading);
+                }
+                for (k, r) in (s..e).zip(&self.phrase_readings[mat.pattern()]) {
+                    f(starts[k], starts[k + 1], Some(r));
+                }
+                next = e;
+            }
+        }
+        for k in next..starts.len() - 1 {
+            let reading = self.lookup(char_at(k)).map(|r| r[0].as_str());
+            f(starts[k], starts[k + 1], reading);
+        }
+    }
+}
+
+/// Iterate `(key, numbered readings)` pairs of a pinyin table.
+fn parse_table(dict: &str) -> impl Iterator<Item = (String, Vec<String>)> + '_ {
+    dict.lines().filter_map(|line| {
+        let line = line.split('#').next()?.trim();
+        if line.is_empty() {
+            return None;
+        }
+        let (key, readings) = line.split_once('\t').or_else(|| line.split_once(':'))?;
+        let key = key.trim();
+        let key = match key.strip_prefix("U+") {
+            Some(hex) => char::from_u32(u32::from_str_radix(hex, 16).ok()?)?.to_string(),
+            None => key.to_string(),
+        };
+        let readings: Vec<String> = readings
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|r| !r.is_empty())
+            .filter_map(numbered)
+            .collect();
+        (!key.is_empty() && !readings.is_empty()).then_some((key, readings))
+    })
+}
+
+/// Convert a reading (`zhōng`, `zhong1`, `lǜ`, `lü4`, `ma`) to numbered form (`zhong1`).
+fn numbered(reading: &str) -> Option<String> {
+    let mut body = String::with_capacity(reading.len());
+    let mut tone = None;
+    for c in reading.nfd() {
+        match c {
+            '\u{0304}' => tone = Some('1'),
+            '\u{0301}' => tone = Some('2'),
+            '\u{030C}' => tone = Some('3'),
+            '\u{0300}' => tone = Some('4'),
+            // u + diaeresis = ü
+            '\u{0308}' if body.ends_with('u') => {
+                body.pop();
+                body.push('v');
+            }
+            '1'..='5' => tone = Some(c),
+            c if c.is_ascii_alphabetic() => body.push(c.to_ascii_lowercase()),
+            _ => return None,
+        }
+    }
+    if body.is_empty() {
+        return None;
+    }
+    body.push(tone.unwrap_or('5'));
+    Some(body)
+}
+
+/// Write a numbered reading in `style`.
+fn render(reading: &str, style: PinyinStyle, out: &mut String) {
+    let (body, tone) = match reading.as_bytes().last() {
+        Some(d @ b'1'..=b'5') => (&reading[..reading.len() - 1], d - b'0'),
+        _ => (reading, 5),
+    };
+    match style {
+        PinyinStyle::Toneless => out.push_str(body),
+        PinyinStyle::ToneNumbers => {
+            out.push_str(body);
+            out.push(char::from(b'0' + tone));
+        }
+        PinyinStyle::Initials => out.extend(body.chars().next()),
+        PinyinStyle::ToneMarks => {
+            let mark_at = tone_mark_position(body);
+            for (i, c) in body.char_indices() {
+                let c = if c == 'v' { 'ü' } else { c };
+                match mark_at {
+                    Some(pos) if pos == i && tone < 5 => out.push(with_tone(c, tone)),
+                    _ => out.push(c),
+                }
+            }
+        }
+    }
+}
+
+/// Byte index of the vowel that carries the tone mark: `a` or `e` if present, the `o`
+/// of `ou`, otherwise the last vowel.
+fn tone_mark_position(body: &str) -> Option<usize> {
+    body.find('a')
+        .or_else(|| body.find('e'))
+        .or_else(|| body.find("ou"))
+        .or_else(|| body.rfind(['i', 'o', 'u', 'v']))
+}
+
+fn with_tone(vowel: char, tone: u8) -> char {
+    let marks: [char; 4] = match vowel {
+        'a' => ['ā', 'á', 'ǎ', 'à'],
+        'e' => ['ē', 'é', 'ě', 'è'],
+        'i' => ['ī', 'í', 'ǐ', 'ì'],
+        'o' => ['ō', 'ó', 'ǒ', 'ò'],
+        'u' => ['ū', 'ú', 'ǔ', 'ù'],
+        'ü' => ['ǖ', 'ǘ', 'ǚ', 'ǜ'],
+        c => return c,
+    };
+    marks[usize::from(tone - 1)]
+}
+
+/// The bundled dictionary.
+pub fn bundled() -> &'static PinyinDict {
+    static DICT: OnceLock<PinyinDict> = OnceLock::new();
+    DICT.get_or_init(|| PinyinDict::from_tables(CHARS, PHRASES))
+}
+
+/// Romanize `text` with the bundled dictionary. See [`PinyinDict::to_pinyin`].
+pub fn to_pinyin(text: &str, style: PinyinStyle) -> String {
+    bundled().to_pinyin(text, style)
+}
+
+/// Like [`to_pinyin`], but writes into an existing `String`.
+pub fn to_pinyin_into(text: &str, style: PinyinStyle, out: &mut String) {
+    bundled().to_pinyin_into(text, style, out)
+}
+
+/// Initials-only key: `"北京"` → `"bj"`.
+pub fn initials(text: &str) -> String {
+    to_pinyin(text, PinyinStyle::Initials)
+}
+
+/// Syllables with **char** offsets, using the bundled dictionary.
+/// See [`PinyinDict::syllables_with_offsets`].
+pub fn syllables_with_offsets(text: &str, style: PinyinStyle) -> Vec<Token> {
+    bundled().syllables_with_offsets(text, style)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toneless_and_initials() {
+        assert_eq!(to_pinyin("北京", PinyinStyle::Toneless), "beijing");
+        assert_eq!(initials("北京"), "bj");
+        assert_eq!(
+            to_pinyin("北京 2008!", PinyinStyle::Toneless),
+            "beijing 2008!"
+        );
+        assert_eq!(to_pinyin("绿色", PinyinStyle::Toneless), "lvse");
+    }
+
+    #[test]
+    fn test_tone_styles() {
+        assert_eq!(to_pinyin("北京", PinyinStyle::ToneMarks), "běijīng");
+        assert_eq!(to_pinyin("北京", PinyinStyle::ToneNumbers), "bei3jing1");
+        assert_eq!(to_pinyin("绿", PinyinStyle::ToneMarks), "lǜ");
+        assert_eq!(to_pinyin("有", PinyinStyle::ToneMarks), "yǒu");
+        assert_eq!(to_pinyin("水", PinyinStyle::ToneMarks), "shuǐ");
+        assert_eq!(to_pinyin("们", PinyinStyle::ToneMarks), "men");
+    }
+
+    #[test]
+    fn test_polyphonic_phrases() {
+        let toneless = |s| to_pinyin(s, PinyinStyle::Toneless);
+        assert_eq!(toneless("银行"), "yinhang");
+        assert_eq!(toneless("行走"), "xingzou");
+        assert_eq!(toneless("长大"), "zhangda");
+        assert_eq!(toneless("长城"), "changcheng");
+        assert_eq!(toneless("音乐"), "yinyue");
+        assert_eq!(toneless("快乐"), "kuaile");
+        assert_eq!(toneless("重庆"), "chongqing");
+    }
+
+    #[test]
+    fn test_traditional_input() {
+        assert_eq!(to_pinyin("銀行", PinyinStyle::Toneless), "yinhang");
+        assert_eq!(initials("臺灣"), "tw");
+    }
+
+    #[test]
+    fn test_offsets_refer_to_source() {
+        let toks = syllables_with_offsets("去 北京", PinyinStyle::Toneless);
+        let got: Vec<_> = toks
+            .iter()
+            .map(|t| (t.text.as_str(), t.start, t.end))
+            .collect();
+        assert_eq!(got, [("qu", 0, 1), ("bei", 2, 3), ("jing", 3, 4)]);
+
+        let bytes =
+            bundled().syllables_with_offsets_in("a北京", PinyinStyle::Initials, OffsetUnit::Byte);
+        assert_eq!((bytes[1].start, bytes[1].end), (4, 7));
+    }
+
+    #[test]
+    fn test_from_tables_accepts_pinyin_data_format() {
+        let dict = PinyinDict::from_tables(
+            "U+4E2D: zhōng,zhòng  # 中\nU+56FD: guó  # 国\n",
+            "中国: zhōng guó\n中奖: zhòng jiǎng\n",
+        );
+        assert_eq!(dict.readings('中').unwrap(), ["zhong1", "zhong4"]);
+        assert_eq!(
+            dict.to_pinyin("中国", PinyinStyle::ToneNumbers),
+            "zhong1guo2"
+        );
+        assert_eq!(dict.to_pinyin("中", PinyinStyle::ToneMarks), "zhōng");
+        assert_eq!(numbered("lǜ").as_deref(), Some("lv4"));
+    }
+
+    #[test]
+    fn test_bundled_tables_parse() {
+        for dict in [CHARS, PHRASES] {
+            for line in dict.lines().filter(|l| !l.starts_with('#')) {
+                let (_, readings) = line.split_once('\t').expect("missing tab");
+                assert!(
+                    readings
+                        .split(' ')
+                        .all(|r| numbered(r).as_deref() == Some(r)),
+                    "malformed entry: {line:?}"
+                );
+            }
+        }
+        let phrases = PHRASES.lines().filter(|l| !l.starts_with('#')).count();
+        assert_eq!(bundled().phrase_readings.len(), phrases);
+    }
+}
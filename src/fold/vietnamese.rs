@@ -0,0 +1,228 @@
+//! Vietnamese tone-mark placement.
+//!
+//! In syllables with the open rhymes *oa*, *oe* and *uy*, the tone mark goes on the first
+//! vowel in the "old" style (hóa, hòe, thúy) and on the second in the "new" style (hoá,
+//! hoè, thuý). Both are in wide use, and combined with composed vs decomposed input the
+//! same word can have several encodings even after NFC. Stripping diacritics would make
+//! them match, but it also merges unrelated words (ma, má, mà, mả, mã, mạ), so for
+//! Vietnamese it is better to keep the marks and put them in one place.
+//!
+//! Each syllable's tone mark is moved to the vowel chosen by the requested
+//! [`ToneStyle`]. Words that do not parse as a single Vietnamese syllable with one tone
+//! mark (e.g. `café`, `naïve`) are left as they are. Output is NFC.
+
+use unicode_normalization::UnicodeNormalization;
+
+/// Where to put the tone mark in *oa*, *oe* and *uy* without a final consonant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ToneStyle {
+    /// On the first vowel: hóa, hòe, thúy.
+    Old,
+    /// On the second vowel: hoá, hoè, thuý.
+    #[default]
+    New,
+}
+
+/// Combining acute, grave, hook above, tilde and dot below.
+fn is_tone(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0301}' | '\u{0300}' | '\u{0309}' | '\u{0303}' | '\u{0323}'
+    )
+}
+
+/// Combining circumflex, breve and horn (â ê ô, ă, ơ ư).
+fn is_vowel_modifier(c: char) -> bool {
+    matches!(c, '\u{0302}' | '\u{0306}' | '\u{031B}')
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u' | 'y')
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphabetic()
+        || matches!(c, 'đ' | 'Đ')
+        || is_tone(c)
+        || is_vowel_modifier(c)
+        || c == '\u{0308}' // so that words like "naïve" stay one (untouched) word
+}
+
+/// A base letter and the combining marks that follow it (NFD).
+struct Letter {
+    base: char,
+    marks: Vec<char>,
+}
+
+impl Letter {
+    fn is_modified_vowel(&self) -> bool {
+        is_vowel(self.base) && self.marks.iter().any(|&m| is_vowel_modifier(m))
+    }
+}
+
+/// Normalize tone placement with [`ToneStyle::default`] (new style).
+pub fn normalize(text: &str) -> String {
+    normalize_with(text, ToneStyle::default())
+}
+
+/// Normalize tone placement to `style`.
+pub fn normalize_with(text: &str, style: ToneStyle) -> String {
+    let mut out = String::with_capacity(text.len());
+    normalize_into(text, style, &mut out);
+    out
+}
+
+/// Like [`normalize_with`], but writes into an existing `String`.
+pub fn normalize_into(text: &str, style: ToneStyle, out: &mut String) {
+    out.clear();
+    out.reserve(text.len());
+    let mut decomposed = String::with_capacity(text.len() + text.len() / 2);
+    let mut word: Vec<Letter> = Vec::new();
+    for c in text.nfd() {
+        if !is_word_char(c) {
+            flush_word(&mut word, style, &mut decomposed);
+            decomposed.push(c);
+        } else if c.is_alphabetic() {
+            word.push(Letter {
+                base: c,
+                marks: Vec::new(),
+            });
+        } else if let Some(letter) = word.last_mut() {
+            letter.marks.push(c);
+        } else {
+            decomposed.push(c);
+        }
+    }
+    flush_word(&mut word, style, &mut decomposed);
+    out.extend(decomposed.nfc());
+}
+
+fn flush_word(word: &mut Vec<Letter>, style: ToneStyle, out: &mut String) {
+    if let Some(target) = tone_target(word, style) {
+        let mut tone = None;
+        for letter in word.iter_mut() {
+            letter.marks.retain(|&m| {
+                let t = is_tone(m);
+                if t {
+                    tone = Some(m);
+                }
+                !t
+            });
+        }
+        word[target].marks.extend(tone);
+    }
+    for letter in word.drain(..) {
+        out.push(letter.base);
+        out.extend(letter.marks);
+    }
+}
+
+/// Index of the letter that should carry the tone mark, or `None` if the word should be
+/// left alone (no tone, several tones, or not a Vietnamese syllable).
+fn tone_target(word: &[Letter], style: ToneStyle) -> Option<usize> {
+    let tones = word
+        .iter()
+        .flat_map(|l| &l.marks)
+        .filter(|&&m| is_tone(m))
+        .count();
+    let foreign_marks = word
+        .iter()
+        .flat_map(|l| &l.marks)
+        .any(|&m| !is_tone(m) && !is_vowel_modifier(m));
+    if tones != 1 || foreign_marks {
+        return None;
+    }
+
+    let lower = |i: usize| word[i].base.to_ascii_lowercase();
+    let mut start = word.iter().position(|l| is_vowel(l.base))?;
+    let mut end = start;
+    while end < word.len() && is_vowel(word[end].base) {
+        end += 1;
+    }
+    // "qu" and "gi" (before another vowel) are onsets: quý, giường.
+    let onset: String = (0..start).map(lower).collect();
+    let plain = |i: usize| word[i].marks.iter().all(|&m| is_tone(m));
+    if end - start > 1
+        && plain(start)
+        && ((onset == "q" && lower(start) == 'u') || (onset == "g" && lower(start) == 'i'))
+    {
+        start += 1;
+    }
+    let coda: String = (end..word.len()).map(lower).collect();
+    if !matches!(
+        coda.as_str(),
+        "" | "c" | "ch" | "m" | "n" | "ng" | "nh" | "p" | "t"
+    ) {
+        return None;
+    }
+
+    if let Some(i) = (start..end).rev().find(|&i| word[i].is_modified_vowel()) {
+        return Some(i);
+    }
+    match end - start {
+        1 => Some(start),
+        3 => Some(start + 1),
+        2 if !coda.is_empty() => Some(start + 1),
+        2 => {
+            let rhyme: String = (start..end).map(lower).collect();
+            match (rhyme.as_str(), style) {
+                ("oa" | "oe" | "uy", ToneStyle::New) => Some(start + 1),
+                _ => Some(start),
+            }
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_old_and_new_style() {
+        assert_eq!(normalize("hóa hòe thúy"), "hoá hoè thuý");
+        assert_eq!(
+            normalize_with("hoá hoè thuý", ToneStyle::Old),
+            "hóa hòe thúy"
+        );
+        assert_eq!(normalize("Hóa học"), "Hoá học");
+        assert_eq!(normalize("HÓA"), "HOÁ");
+    }
+
+    #[test]
+    fn test_unambiguous_syllables_are_stable() {
+        for s in [
+            "hoàng",
+            "quý",
+            "của",
+            "mùa",
+            "giường",
+            "gì",
+            "người",
+            "khuỷu",
+            "ngoài",
+        ] {
+            assert_eq!(normalize_with(s, ToneStyle::Old), s);
+            assert_eq!(normalize_with(s, ToneStyle::New), s);
+        }
+    }
+
+    #[test]
+    fn test_misplaced_marks_are_moved() {
+        // Tone typed on the wrong vowel of a rhyme with a final consonant or modifier.
+        assert_eq!(normalize("hòang"), "hoàng");
+        assert_eq!(normalize("ngừơi"), "người");
+    }
+
+    #[test]
+    fn test_decomposed_input() {
+        assert_eq!(normalize("hoa\u{0301}"), "hoá");
+        assert_eq!(normalize("ho\u{0301}a"), "hoá");
+    }
+
+    #[test]
+    fn test_non_vietnamese_words_untouched() {
+        assert_eq!(normalize("café naïve Việt Nam"), "café naïve Việt Nam");
+    }
+}
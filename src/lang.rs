@@ -0,0 +1,82 @@
+//! Language identifiers shared by the language-specific modules ([`crate::stopwords`],
+//! [`crate::stem`], [`crate::tokenize::SentenceSplitter`]).
+
+/// A language with built-in resources in this crate.
+///
+/// Parsed from the same codes everywhere: the ISO 639-1 code or the English name,
+/// case-insensitively (`"de"`, `"German"`, `"GERMAN"`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Language {
+    English,
+    French,
+    German,
+    Spanish,
+    Italian,
+    Portuguese,
+    Dutch,
+    Russian,
+    Swedish,
+}
+
+impl Language {
+    /// Every supported language.
+    pub const ALL: [Language; 9] = [
+        Language::English,
+        Language::French,
+        Language::German,
+        Language::Spanish,
+        Language::Italian,
+        Language::Portuguese,
+        Language::Dutch,
+        Language::Russian,
+        Language::Swedish,
+    ];
+
+    /// Parse an ISO 639-1 code or English language name (case-insensitive).
+    pub fn from_code(code: &str) -> Option<Language> {
+        let lang = match code.to_lowercase().as_str() {
+            "en" | "english" => Language::English,
+            "fr" | "french" => Language::French,
+            "de" | "german" => Language::German,
+            "es" | "spanish" => Language::Spanish,
+            "it" | "italian" => Language::Italian,
+            "pt" | "portuguese" => Language::Portuguese,
+            "nl" | "dutch" => Language::Dutch,
+            "ru" | "russian" => Language::Russian,
+            "sv" | "swedish" => Language::Swedish,
+            _ => return None,
+        };
+        Some(lang)
+    }
+
+    /// The ISO 639-1 code.
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::French => "fr",
+            Language::German => "de",
+            Language::Spanish => "es",
+            Language::Italian => "it",
+            Language::Portuguese => "pt",
+            Language::Dutch => "nl",
+            Language::Russian => "ru",
+            Language::Swedish => "sv",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_round_trip() {
+        for lang in Language::ALL {
+            assert_eq!(Language::from_code(lang.code()), Some(lang));
+        }
+        assert_eq!(Language::from_code("German"), Some(Language::German));
+        assert_eq!(Language::from_code("PT"), Some(Language::Portuguese));
+        assert_eq!(Language::from_code("xx"), None);
+    }
+}
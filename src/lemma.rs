@@ -0,0 +1,394 @@
+//! Dictionary lemmatization.
+//!
+//! Where a stemmer cuts words down to search keys (`"studies"` → `"studi"`), a
+//! lemmatizer maps each inflected form to its dictionary headword: `"mice"` → `"mouse"`,
+//! `"better"` → `"good"`, `"studies"` → `"study"`.
+//!
+//! A [`Lemmatizer`] is a form → lemma table, optionally keyed by part of speech
+//! ([`Pos`]), plus an optional rule-based English suffix fallback for forms the table
+//! does not cover (`"walked"` → `"walk"`, `"boxes"` → `"box"`, `"hoping"` → `"hope"`).
+//! The bundled English table ([`english`]) lists irregular verbs, nouns and
+//! comparatives and the words the suffix rules would get wrong; load larger tables
+//! (or tables for other languages) with [`Lemmatizer::from_tsv`].
+//!
+//! Lookups are case-insensitive: forms are lowercased when loaded and looked up
+//! lowercased, and lemmas are returned as stored.
+
+mod english;
+
+use crate::tokenize::Token;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+static ENGLISH: &str = include_str!("lemma/data/english.tsv");
+
+/// Coarse part of speech, used to pick between lemmas of an ambiguous form
+/// (`"saw"`: noun *saw*, verb *see*).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Pos {
+    Noun,
+    Verb,
+    Adjective,
+    Adverb,
+}
+
+impl Pos {
+    /// Parse a tag in Universal Dependencies (`NOUN`, `PROPN`, `VERB`, `AUX`, `ADJ`,
+    /// `ADV`), Penn Treebank (`NN*`, `VB*`, `JJ*`, `RB*`) or WordNet (`n`, `v`, `a`, `s`,
+    /// `r`) form, case-insensitively. Other tags give `None`.
+    pub fn from_tag(tag: &str) -> Option<Pos> {
+        let tag = tag.trim().to_ascii_uppercase();
+        match tag.as_str() {
+            "NOUN" | "PROPN" | "N" => Some(Pos::Noun),
+            "VERB" | "AUX" | "V" => Some(Pos::Verb),
+            "ADJ" | "A" | "S" => Some(Pos::Adjective),
+            "ADV" | "R" => Some(Pos::Adverb),
+            t if t.starts_with("NN") => Some(Pos::Noun),
+            t if t.starts_with("VB") => Some(Pos::Verb),
+            t if t.starts_with("JJ") => Some(Pos::Adjective),
+            t if t.starts_with("RB") => Some(Pos::Adverb),
+            _ => None,
+        }
+    }
+}
+
+/// A form → lemma table with an optional English suffix fallback.
+///
+/// All strings live in one buffer; entries are sorted by form and found by binary
+/// search, and each distinct lemma is stored once.
+#[derive(Debug, Clone, Default)]
+pub struct Lemmatizer {
+    buf: String,
+    /// Sorted by (form, pos); untagged entries sort before tagged ones.
+    entries: Vec<Entry>,
+    english_rules: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    form: StrRef,
+    lemma: StrRef,
+    pos: Option<Pos>,
+}
+
+/// A `start..start + len` slice of `Lemmatizer::buf`.
+#[derive(Debug, Clone, Copy)]
+struct StrRef {
+    start: u32,
+    len: u32,
+}
+
+impl Lemmatizer {
+    /// An empty table without fallback rules: every word is its own lemma.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a table from TSV text.
+    ///
+    /// One entry per line, either `form<TAB>lemma` or `form<TAB>pos<TAB>lemma`, where
+    /// `pos` is any tag accepted by [`Pos::from_tag`]. Blank lines, lines starting with
+    /// `#`, and lines with an unknown tag or the wrong number of fields are ignored. If
+    /// a (form, pos) pair occurs twice, the later line wins.
+    pub fn from_tsv(tsv: &str) -> Self {
+        let mut lemmatizer = Self::new();
+        lemmatizer.add_tsv(tsv);
+        lemmatizer
+    }
+
+    /// Add the entries of another TSV table (see [`Lemmatizer::from_tsv`]). Entries
+    /// already present for the same (form, pos) are replaced.
+    pub fn add_tsv(&mut self, tsv: &str) {
+        let mut rows: Vec<(String, Option<Pos>, String)> = self
+            .entries
+            .iter()
+            .map(|e| {
+                (
+                    self.str(e.form).to_string(),
+                    e.pos,
+                    self.str(e.lemma).to_string(),
+                )
+            })
+            .collect();
+        rows.extend(parse_tsv(tsv));
+        self.rebuild(rows);
+    }
+
+    /// Enable or disable the rule-based English suffix fallback for words not in the
+    /// table.
+    pub fn with_english_rules(mut self, enabled: bool) -> Self {
+        self.english_rules = enabled;
+        self
+    }
+
+    /// Number of (form, pos) entries in the table.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Look `word` up in the table only.
+    ///
+    /// With a `pos`, an entry for that part of speech is preferred over an untagged
+    /// one, and entries for other parts of speech are not used. Without one, the
+    /// untagged entry is used if there is one, else the first tagged entry in [`Pos`]
+    /// order (noun, verb, adjective, adverb).
+    pub fn lookup(&self, word: &str, pos: Option<Pos>) -> Option<&str> {
+        let word = word.to_lowercase();
+        let from = self
+            .entries
+            .partition_point(|e| self.str(e.form) < word.as_str());
+        let candidates = self.entries[from..]
+            .iter()
+            .take_while(|e| self.str(e.form) == word);
+        let entry = match pos {
+            None => candidates.min_by_key(|e| e.pos),
+            Some(p) => candidates
+                .filter(|e| e.pos.is_none() || e.pos == Some(p))
+                .max_by_key(|e| e.pos),
+        };
+        entry.map(|e| self.str(e.lemma))
+    }
+
+    /// The lemma of `word`: the table entry if there is one, else the suffix fallback
+    /// (if enabled), else the lowercased word.
+    pub fn lemmatize(&self, word: &str) -> String {
+        self.lemmatize_pos(word, None)
+    }
+
+    /// Like [`Lemmatizer::lemmatize`], for a word with a known part of speech.
+    ///
+    /// `"better"` is `"good"` as an adjective and `"well"` as an adverb; the fallback
+    /// only strips comparative `-er`/`-est` from adjectives.
+    pub fn lemmatize_as(&self, word: &str, pos: Pos) -> String {
+        self.lemmatize_pos(word, Some(pos))
+    }
+
+    fn lemmatize_pos(&self, word: &str, pos: Option<Pos>) -> String {
+        if let Some(lemma) = self.lookup(word, pos) {
+            return lemma.to_string();
+        }
+        let word = word.to_lowercase();
+        if self.english_rules {
+            if let Some(lemma) = english::lemma(&word, pos) {
+                return lemma;
+            }
+        }
+        word
+    }
+
+    /// Lemmatize every token, keeping its offsets (which still point at the original
+    /// word). Tokens that are not words ([`TokenKind::is_word`]) are copied unchanged.
+    ///
+    /// [`TokenKind::is_word`]: crate::tokenize::TokenKind::is_word
+    pub fn lemmatize_tokens(&self, tokens: &[Token]) -> Vec<Token> {
+        tokens
+            .iter()
+            .map(|t| Token {
+                text: if t.kind.is_word() {
+                    self.lemmatize(&t.text)
+                } else {
+                    t.text.clone()
+                },
+                start: t.start,
+                end: t.end,
+                kind: t.kind,
+            })
+            .collect()
+    }
+
+    fn str(&self, s: StrRef) -> &str {
+        let start = s.start as usize;
+        &self.buf[start..start + s.len as usize]
+    }
+
+    fn rebuild(&mut self, mut rows: Vec<(String, Option<Pos>, String)>) {
+        // Stable sort, so that of two rows with the same key the later one comes last.
+        rows.sort_by(|a, b| (&a.0, a.1).cmp(&(&b.0, b.1)));
+        let mut deduped: Vec<(String, Option<Pos>, String)> = Vec::with_capacity(rows.len());
+        for row in rows {
+            match deduped.last_mut() {
+                Some(last) if last.0 == row.0 && last.1 == row.1 => *last = row,
+                _ => deduped.push(row),
+            }
+        }
+
+        let mut buf = String::new();
+        let mut entries = Vec::with_capacity(deduped.len());
+        let mut lemmas: HashMap<String, StrRef> = HashMap::new();
+        let push = |buf: &mut String, s: &str| {
+            let r = StrRef {
+                start: u32::try_from(buf.len()).expect("lemma table exceeds 4 GiB"),
+                len: s.len() as u32,
+            };
+            buf.push_str(s);
+            r
+        };
+        let mut prev_form: Option<(String, StrRef)> = None;
+        for (form, pos, lemma) in deduped {
+            let form_ref = match &prev_form {
+                Some((f, r)) if *f == form => *r,
+                _ => {
+                    let r = push(&mut buf, &form);
+                    prev_form = Some((form, r));
+                    r
+                }
+            };
+            let lemma_ref = match lemmas.get(&lemma) {
+                Some(&r) => r,
+                None => {
+                    let r = push(&mut buf, &lemma);
+                    lemmas.insert(lemma, r);
+                    r
+                }
+            };
+            entries.push(Entry {
+                form: form_ref,
+                lemma: lemma_ref,
+                pos,
+            });
+        }
+        buf.shrink_to_fit();
+        self.buf = buf;
+        self.entries = entries;
+    }
+}
+
+fn parse_tsv(tsv: &str) -> impl Iterator<Item = (String, Option<Pos>, String)> + '_ {
+    tsv.lines().filter_map(|line| {
+        let line = line.trim_end_matches('\r');
+        if line.trim().is_empty() || line.starts_with('#') {
+            return None;
+        }
+        let fields: Vec<&str> = line.split('\t').map(str::trim).collect();
+        let (form, pos, lemma) = match fields[..] {
+            [form, lemma] => (form, None, lemma),
+            [form, tag, lemma] => (form, Some(Pos::from_tag(tag)?), lemma),
+            _ => return None,
+        };
+        (!form.is_empty() && !lemma.is_empty())
+            .then(|| (form.to_lowercase(), pos, lemma.to_string()))
+    })
+}
+
+/// The bundled English table, with the suffix fallback enabled.
+pub fn english() -> &'static Lemmatizer {
+    static LEMMATIZER: OnceLock<Lemmatizer> = OnceLock::new();
+    LEMMATIZER.get_or_init(|| Lemmatizer::from_tsv(ENGLISH).with_english_rules(true))
+}
+
+/// Lemmatize an English word with the bundled table. See [`Lemmatizer::lemmatize`].
+pub fn lemmatize(word: &str) -> String {
+    english().lemmatize(word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenize::tokenize_with_offsets;
+
+    #[test]
+    fn test_bundled_english() {
+        let cases = [
+            ("mice", "mouse"),
+            ("Better", "good"),
+            ("went", "go"),
+            ("was", "be"),
+            ("children", "child"),
+            ("criteria", "criterion"),
+            ("saw", "see"),
+            ("news", "news"),
+            ("during", "during"),
+        ];
+        for (word, lemma) in cases {
+            assert_eq!(lemmatize(word), lemma, "{word}");
+        }
+        let en = english();
+        assert_eq!(en.lemmatize_as("better", Pos::Adverb), "well");
+        assert_eq!(en.lemmatize_as("lives", Pos::Noun), "life");
+        assert_eq!(en.lemmatize_as("lives", Pos::Verb), "live");
+        assert_eq!(en.lemmatize_as("saw", Pos::Noun), "saw");
+    }
+
+    #[test]
+    fn test_english_rules() {
+        let cases = [
+            ("cats", "cat"),
+            ("boxes", "box"),
+            ("churches", "church"),
+            ("studies", "study"),
+            ("ties", "tie"),
+            ("glass", "glass"),
+            ("walked", "walk"),
+            ("played", "play"),
+            ("carried", "carry"),
+            ("stopped", "stop"),
+            ("running", "run"),
+            ("falling", "fall"),
+            ("hoping", "hope"),
+            ("opened", "open"),
+            ("relating", "relate"),
+            ("realized", "realize"),
+            ("produced", "produce"),
+            ("caused", "cause"),
+            ("charged", "charge"),
+            ("changed", "change"),
+            ("computed", "compute"),
+            ("handled", "handle"),
+            ("curled", "curl"),
+            ("thing", "thing"),
+            ("faster", "faster"),
+        ];
+        for (word, lemma) in cases {
+            assert_eq!(lemmatize(word), lemma, "{word}");
+        }
+        let en = english();
+        assert_eq!(en.lemmatize_as("bigger", Pos::Adjective), "big");
+        assert_eq!(en.lemmatize_as("happiest", Pos::Adjective), "happy");
+        assert_eq!(en.lemmatize_as("larger", Pos::Adjective), "large");
+        assert_eq!(en.lemmatize_as("taller", Pos::Adjective), "tall");
+        assert_eq!(en.lemmatize_as("boxes", Pos::Adjective), "boxes");
+    }
+
+    #[test]
+    fn test_from_tsv() {
+        let tsv = "# comment\nÄpfel\tApfel\nsaw\tVB\tsee\nsaw\tNN\tsaw\r\n\nbad line\nx\tFOO\ty\nÄpfel\tapfel\n";
+        let lem = Lemmatizer::from_tsv(tsv);
+        assert_eq!(lem.len(), 3);
+        assert_eq!(lem.lookup("äpfel", None), Some("apfel"));
+        assert_eq!(lem.lookup("saw", None), Some("saw"));
+        assert_eq!(lem.lookup("saw", Some(Pos::Verb)), Some("see"));
+        assert_eq!(lem.lookup("saw", Some(Pos::Adverb)), None);
+        assert_eq!(lem.lookup("x", None), None);
+        // No fallback rules unless asked for.
+        assert_eq!(lem.lemmatize("Walked"), "walked");
+        assert_eq!(
+            lem.clone().with_english_rules(true).lemmatize("walked"),
+            "walk"
+        );
+    }
+
+    #[test]
+    fn test_add_tsv_overrides() {
+        let mut lem = Lemmatizer::from_tsv(ENGLISH);
+        let before = lem.len();
+        lem.add_tsv("data\tdatum\nmice\tmouse-pl\n");
+        assert_eq!(lem.len(), before + 1);
+        assert_eq!(lem.lookup("data", None), Some("datum"));
+        assert_eq!(lem.lookup("mice", None), Some("mouse-pl"));
+        assert_eq!(lem.lookup("went", None), Some("go"));
+    }
+
+    #[test]
+    fn test_lemmatize_tokens_keeps_offsets() {
+        let tokens = tokenize_with_offsets("The mice were running");
+        let lemmas = english().lemmatize_tokens(&tokens);
+        let texts: Vec<&str> = lemmas.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(texts, ["the", "mouse", "be", "run"]);
+        assert_eq!((lemmas[1].start, lemmas[1].end), (4, 8));
+    }
+}
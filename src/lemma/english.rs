@@ -0,0 +1,144 @@
+//! Rule-based English suffix fallback.
+//!
+//! Strips regular inflections (`-s`/`-es`/`-ies`, `-ed`/`-ied`, `-ing`, and comparative
+//! `-er`/`-est` for adjectives) and repairs the stem the way English spelling changed
+//! it: a doubled final consonant is undoubled (`stopped` → `stop`) and a dropped `e` is
+//! restored where the stem could not end without one (`hoping` → `hope`,
+//! `produced` → `produce`). There is no lexicon to check candidates against, so the
+//! rules are heuristics; exceptions belong in the table.
+
+use super::Pos;
+
+/// Lemma of a lowercase ASCII word, or `None` if no rule applies.
+pub(super) fn lemma(word: &str, pos: Option<Pos>) -> Option<String> {
+    if word.len() <= 3 || !word.bytes().all(|b| b.is_ascii_lowercase()) {
+        return None;
+    }
+    match pos {
+        Some(Pos::Noun) => s_form(word),
+        Some(Pos::Adjective) => comparative(word),
+        Some(Pos::Adverb) => None,
+        Some(Pos::Verb) | None => s_form(word)
+            .or_else(|| past(word))
+            .or_else(|| present_participle(word)),
+    }
+}
+
+/// Plural nouns and third-person singular verbs.
+fn s_form(w: &str) -> Option<String> {
+    if ["ss", "us", "is"].iter().any(|s| w.ends_with(s)) {
+        return None;
+    }
+    if let Some(stem) = w.strip_suffix("ies") {
+        // `studies` → `study`, but `ties` → `tie`.
+        return Some(if stem.len() > 1 {
+            format!("{stem}y")
+        } else {
+            format!("{stem}ie")
+        });
+    }
+    if ["sses", "xes", "ches", "shes", "zzes"]
+        .iter()
+        .any(|s| w.ends_with(s))
+    {
+        return Some(w[..w.len() - 2].to_string());
+    }
+    w.strip_suffix('s').map(str::to_string)
+}
+
+fn past(w: &str) -> Option<String> {
+    if w.ends_with("eed") {
+        return None;
+    }
+    if let Some(stem) = w.strip_suffix("ied") {
+        return Some(if stem.len() > 1 {
+            format!("{stem}y")
+        } else {
+            format!("{stem}ie")
+        });
+    }
+    let stem = w.strip_suffix("ed")?;
+    has_vowel(stem).then(|| restore(stem))
+}
+
+fn present_participle(w: &str) -> Option<String> {
+    let stem = w.strip_suffix("ing")?;
+    (stem.len() >= 2 && has_vowel(stem)).then(|| restore(stem))
+}
+
+fn comparative(w: &str) -> Option<String> {
+    let stem = w.strip_suffix("est").or_else(|| w.strip_suffix("er"))?;
+    if let Some(base) = stem.strip_suffix('i') {
+        if base.len() > 1 {
+            return Some(format!("{base}y"));
+        }
+    }
+    (stem.len() >= 2 && has_vowel(stem)).then(|| restore(stem))
+}
+
+/// Undo the spelling changes made when the suffix was added.
+fn restore(stem: &str) -> String {
+    let b = stem.as_bytes();
+    let n = b.len();
+    let last = b[n - 1];
+    if n > 3 && b[n - 2] == last && !is_vowel(last) && !b"lsz".contains(&last) {
+        return stem[..n - 1].to_string();
+    }
+    if needs_e(b) {
+        return format!("{stem}e");
+    }
+    stem.to_string()
+}
+
+/// Whether a stem (at least two letters) must have lost a final `e`.
+fn needs_e(b: &[u8]) -> bool {
+    let n = b.len();
+    let last = b[n - 1];
+    let prev = b[n - 2];
+    let before_prev_is_vowel = n >= 3 && is_vowel(b[n - 3]);
+    match last {
+        // No English word ends in `v`, and `-c` before a suffix is `-ce` (`produced`).
+        b'v' | b'c' => true,
+        // `engaged`, `charged`, `judged`, `changed`, but not `sing`, `banged`.
+        b'g' => {
+            is_vowel(prev)
+                || prev == b'r'
+                || prev == b'd'
+                || (prev == b'n' && n > 4 && matches!(b[n - 3], b'a' | b'e'))
+        }
+        // `realized`, `analyzed`, `caused`, `seized`.
+        b'z' if prev == b'i' || prev == b'y' => true,
+        b's' | b'z' if is_vowel(prev) && before_prev_is_vowel => true,
+        // `handled`, `simpler`, but not `curled`, `howled`.
+        b'l' if !is_vowel(prev) && !b"lrw".contains(&prev) => true,
+        // `relating`, `computed`, `secured`, but not `treated`, `shouted`, `poured`.
+        b't' if matches!(prev, b'a' | b'u') && n >= 3 && !before_prev_is_vowel => true,
+        b'r' if prev == b'u' && n >= 3 && !before_prev_is_vowel => true,
+        _ => is_short(b),
+    }
+}
+
+/// A one-syllable stem ending in consonant–vowel–consonant (`hop`, `us`): Porter2's
+/// "short word".
+fn is_short(b: &[u8]) -> bool {
+    let n = b.len();
+    let last = b[n - 1];
+    let short_syllable = if n == 2 {
+        is_vowel(b[0]) && !is_vowel(b[1])
+    } else {
+        !is_vowel(b[n - 3]) && is_vowel(b[n - 2]) && !is_vowel(last) && !b"wxy".contains(&last)
+    };
+    // R1 (after the first non-vowel following a vowel) must be empty.
+    let r1 = (1..n)
+        .find(|&i| is_vowel(b[i - 1]) && !is_vowel(b[i]))
+        .map_or(n, |i| i + 1);
+    short_syllable && r1 >= n
+}
+
+fn is_vowel(b: u8) -> bool {
+    b"aeiouy".contains(&b)
+}
+
+fn has_vowel(s: &str) -> bool {
+    s.bytes().any(is_vowel)
+}
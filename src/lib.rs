@@ -7,17 +7,23 @@
 
 pub mod flash;
 pub mod fold;
+pub mod lang;
+pub mod lemma;
 pub mod ngram;
+pub mod offset;
 pub mod similarity;
+pub mod stem;
 pub mod stopwords;
 pub mod subword;
 pub mod tokenize;
 pub mod unicode;
 
 pub use flash::{FlashText, KeywordMatch};
-pub use fold::{fold, strip_diacritics};
+pub use fold::{fold, fold_letters, strip_diacritics, DiacriticPolicy};
+pub use lang::Language;
+pub use offset::{OffsetIndex, OffsetUnit, Span};
 pub use subword::{BpeTokenizer, SubwordTokenizer};
-pub use tokenize::Token;
+pub use tokenize::{Token, TokenKind, Tokenizer};
 pub use unicode::{nfc, nfkc};
 
 /// Policy/config for constructing normalized keys / comparison forms.
@@ -42,6 +48,36 @@ pub struct ScrubConfig {
     pub case: ScrubCase,
     /// Strip combining marks (diacritics) after normalization + case mapping.
     pub strip_diacritics: bool,
+    /// Which scripts `strip_diacritics` applies to (default: Latin/Greek/Cyrillic accents).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub diacritics: DiacriticPolicy,
+    /// Arabic orthographic normalization (alef/yeh/teh marbuta/hamza folding, tatweel and
+    /// harakat removal). Applied after normalization + case mapping, before diacritics.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub arabic: Option<fold::arabic::ArabicConfig>,
+    /// Hebrew normalization (niqqud/te'amim removal, final-form folding, geresh quotes).
+    /// Applied alongside `arabic`, before diacritics.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub hebrew: Option<fold::hebrew::HebrewConfig>,
+    /// Greek folding (accents and dialytika on Greek letters only, final sigma).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub greek: Option<fold::greek::GreekConfig>,
+    /// Cyrillic folding (ё → е, Ukrainian/Belarusian apostrophe variants).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub cyrillic: Option<fold::cyrillic::CyrillicConfig>,
+    /// Indic canonicalization (nukta, vowel letters, chillus, nasals, orthographic
+    /// ZWJ/ZWNJ). Unlike `remove_zero_width`, keeps joiners that follow a virama.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub indic: Option<fold::indic::IndicConfig>,
+    /// Move Vietnamese tone marks to one canonical vowel per syllable (hóa / hoá).
+    /// Usually combined with `strip_diacritics: false`, since Vietnamese tones are
+    /// lexical.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub vietnamese: Option<fold::vietnamese::ToneStyle>,
+    /// Fold Chinese text to one script (Traditional ↔ Simplified) using the bundled
+    /// OpenCC-style tables, so that e.g. 臺灣 and 台湾 produce the same key.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub chinese: Option<fold::chinese_ts::ChineseScript>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -85,6 +121,14 @@ impl ScrubConfig {
             normalization: ScrubNormalization::Nfkc,
             case: ScrubCase::NfkcCasefold,
             strip_diacritics: true,
+            diacritics: DiacriticPolicy::default(),
+            arabic: None,
+            hebrew: None,
+            greek: None,
+            cyrillic: None,
+            indic: None,
+            vietnamese: None,
+            chinese: None,
         }
     }
 
@@ -99,6 +143,14 @@ impl ScrubConfig {
             normalization: ScrubNormalization::Nfkc,
             case: ScrubCase::Lower,
             strip_diacritics: true,
+            diacritics: DiacriticPolicy::default(),
+            arabic: None,
+            hebrew: None,
+            greek: None,
+            cyrillic: None,
+            indic: None,
+            vietnamese: None,
+            chinese: None,
         }
     }
 
@@ -126,6 +178,14 @@ impl Default for ScrubConfig {
             normalization: ScrubNormalization::Nfc,
             case: ScrubCase::Lower,
             strip_diacritics: true,
+            diacritics: DiacriticPolicy::default(),
+            arabic: None,
+            hebrew: None,
+            greek: None,
+            cyrillic: None,
+            indic: None,
+            vietnamese: None,
+            chinese: None,
         }
     }
 }
@@ -171,8 +231,37 @@ pub fn scrub_with(text: &str, cfg: &ScrubConfig) -> String {
         ScrubCase::NfkcCasefold => fold::fold_nfkc_casefold(&s),
     };
 
+    if let Some(arabic) = &cfg.arabic {
+        fold::arabic::normalize_into(&s, arabic, &mut buf);
+        std::mem::swap(&mut s, &mut buf);
+    }
+    if let Some(hebrew) = &cfg.hebrew {
+        fold::hebrew::normalize_into(&s, hebrew, &mut buf);
+        std::mem::swap(&mut s, &mut buf);
+    }
+    if let Some(greek) = &cfg.greek {
+        fold::greek::normalize_into(&s, greek, &mut buf);
+        std::mem::swap(&mut s, &mut buf);
+    }
+    if let Some(cyrillic) = &cfg.cyrillic {
+        fold::cyrillic::normalize_into(&s, cyrillic, &mut buf);
+        std::mem::swap(&mut s, &mut buf);
+    }
+    if let Some(indic) = &cfg.indic {
+        fold::indic::normalize_into(&s, indic, &mut buf);
+        std::mem::swap(&mut s, &mut buf);
+    }
+    if let Some(style) = cfg.vietnamese {
+        fold::vietnamese::normalize_into(&s, style, &mut buf);
+        std::mem::swap(&mut s, &mut buf);
+    }
+    if let Some(script) = cfg.chinese {
+        fold::chinese_ts::convert_into(&s, script, &mut buf);
+        std::mem::swap(&mut s, &mut buf);
+    }
+
     if cfg.strip_diacritics {
-        s = fold::strip_diacritics(&s);
+        s = fold::strip_diacritics_with(&s, &cfg.diacritics);
     }
 
     // Important ordering: compatibility normalization + diacritics stripping can introduce
@@ -231,6 +320,73 @@ mod tests {
         assert_eq!(out, text);
     }
 
+    #[test]
+    fn test_search_key_keeps_indic_marks_unless_opted_in() {
+        let mut cfg = ScrubConfig::search_key();
+        assert_eq!(scrub_with("Crème क्षि", &cfg), "creme क्षि");
+        cfg.diacritics = DiacriticPolicy::all();
+        assert_eq!(scrub_with("Crème क्षि", &cfg), "creme कषि");
+    }
+
+    #[test]
+    fn test_search_key_with_arabic_normalization() {
+        let mut cfg = ScrubConfig::search_key();
+        cfg.arabic = Some(fold::arabic::ArabicConfig::default());
+        assert_eq!(
+            scrub_with("أَحْمَد  ــ مكتبة", &cfg),
+            scrub_with("احمد مكتبه", &cfg)
+        );
+    }
+
+    #[test]
+    fn test_search_key_with_hebrew_normalization() {
+        let mut cfg = ScrubConfig::search_key();
+        assert_ne!(scrub_with("שָׁלוֹם", &cfg), scrub_with("שלום", &cfg));
+        cfg.hebrew = Some(fold::hebrew::HebrewConfig::default());
+        assert_eq!(scrub_with("שָׁלוֹם", &cfg), scrub_with("שלום", &cfg));
+    }
+
+    #[test]
+    fn test_search_key_with_greek_and_cyrillic_folding() {
+        let mut cfg = ScrubConfig::search_key();
+        cfg.strip_diacritics = false;
+        cfg.greek = Some(fold::greek::GreekConfig::default());
+        cfg.cyrillic = Some(fold::cyrillic::CyrillicConfig::default());
+        assert_eq!(scrub_with("ΛΟΓΟΣ", &cfg), scrub_with("λόγος", &cfg));
+        assert_eq!(scrub_with("Королёв", &cfg), "королев");
+        assert_eq!(scrub_with("п’ять", &cfg), scrub_with("п'ять", &cfg));
+        // Only the targeted rules apply: й and Latin accents survive.
+        assert_eq!(scrub_with("йод café", &cfg), "йод café");
+    }
+
+    #[test]
+    fn test_search_key_with_indic_normalization() {
+        let mut cfg = ScrubConfig::search_key();
+        assert_ne!(scrub_with("हिन्दी", &cfg), scrub_with("हिंदी", &cfg));
+        cfg.indic = Some(fold::indic::IndicConfig::search());
+        assert_eq!(scrub_with("हिन्दी", &cfg), scrub_with("हिंदी", &cfg));
+        assert_eq!(scrub_with("क्\u{200C}ष", &cfg), "क्\u{200C}ष");
+    }
+
+    #[test]
+    fn test_search_key_with_vietnamese_tone_placement() {
+        let mut cfg = ScrubConfig::search_key();
+        cfg.strip_diacritics = false;
+        assert_ne!(scrub_with("Hóa học", &cfg), scrub_with("hoá học", &cfg));
+        cfg.vietnamese = Some(fold::vietnamese::ToneStyle::New);
+        assert_eq!(scrub_with("Hóa học", &cfg), "hoá học");
+        assert_eq!(scrub_with("Hóa học", &cfg), scrub_with("hoá học", &cfg));
+        assert_ne!(scrub_with("má", &cfg), scrub_with("mà", &cfg));
+    }
+
+    #[test]
+    fn test_search_key_with_chinese_folding() {
+        let mut cfg = ScrubConfig::search_key();
+        assert_ne!(scrub_with("臺灣", &cfg), scrub_with("台湾", &cfg));
+        cfg.chinese = Some(fold::chinese_ts::ChineseScript::Simplified);
+        assert_eq!(scrub_with("臺灣", &cfg), scrub_with("台湾", &cfg));
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn test_serde_roundtrip_scrub_config() {
@@ -242,6 +398,14 @@ mod tests {
             normalization: ScrubNormalization::Nfkc,
             case: ScrubCase::Lower,
             strip_diacritics: true,
+            diacritics: DiacriticPolicy::default(),
+            arabic: None,
+            hebrew: None,
+            greek: None,
+            cyrillic: None,
+            indic: None,
+            vietnamese: None,
+            chinese: None,
         };
         let s = serde_json::to_string(&cfg).expect("serialize");
         let de: ScrubConfig = serde_json::from_str(&s).expect("deserialize");
@@ -252,6 +416,14 @@ mod tests {
         assert_eq!(cfg.normalization, de.normalization);
         assert_eq!(cfg.case, de.case);
         assert_eq!(cfg.strip_diacritics, de.strip_diacritics);
+        assert_eq!(cfg.diacritics, de.diacritics);
+        assert_eq!(cfg.arabic, de.arabic);
+        assert_eq!(cfg.hebrew, de.hebrew);
+        assert_eq!(cfg.greek, de.greek);
+        assert_eq!(cfg.cyrillic, de.cyrillic);
+        assert_eq!(cfg.indic, de.indic);
+        assert_eq!(cfg.vietnamese, de.vietnamese);
+        assert_eq!(cfg.chinese, de.chinese);
     }
 
     #[test]
@@ -261,6 +433,7 @@ mod tests {
             text: "東京".to_string(),
             start: 1,
             end: 3,
+            kind: TokenKind::Ideograph,
         };
         let s = serde_json::to_string(&t).expect("serialize");
         let de: Token = serde_json::from_str(&s).expect("deserialize");
@@ -1,5 +1,8 @@
 //! N-gram generation.
 
+use crate::tokenize::Tokenizer;
+use unicode_segmentation::UnicodeSegmentation;
+
 pub fn char_ngrams(text: &str, n: usize) -> Vec<String> {
     let chars: Vec<char> = text.chars().collect();
     if chars.len() < n {
@@ -12,8 +15,18 @@ pub fn char_ngrams(text: &str, n: usize) -> Vec<String> {
     result
 }
 
+/// Like [`char_ngrams`], but over extended grapheme clusters, so that an n-gram never
+/// splits an emoji sequence, a flag or a letter from its combining marks.
+pub fn grapheme_ngrams(text: &str, n: usize) -> Vec<String> {
+    let graphemes: Vec<&str> = text.graphemes(true).collect();
+    if n == 0 || graphemes.len() < n {
+        return Vec::new();
+    }
+    graphemes.windows(n).map(|w| w.concat()).collect()
+}
+
 pub fn word_ngrams(words: &[&str], n: usize) -> Vec<String> {
-    if words.len() < n {
+    if n == 0 || words.len() < n {
         return Vec::new();
     }
     let mut result = Vec::with_capacity(words.len() - n + 1);
@@ -23,6 +36,16 @@ pub fn word_ngrams(words: &[&str], n: usize) -> Vec<String> {
     result
 }
 
+/// Word n-grams of `text`, with words defined by `tokenizer`, joined by a space.
+///
+/// Equivalent to tokenizing and calling [`word_ngrams`] on the token texts.
+pub fn word_ngrams_with(text: &str, n: usize, tokenizer: &dyn Tokenizer) -> Vec<String> {
+    let mut tokens = Vec::new();
+    tokenizer.tokenize_into(text, &mut tokens);
+    let words: Vec<&str> = tokens.iter().map(|t| t.text).collect();
+    word_ngrams(&words, n)
+}
+
 /// Generate token n-grams as vectors of `&str`.
 ///
 /// This is a convenience for callers that want to apply their own hashing/keying
@@ -37,3 +60,16 @@ pub fn token_ngrams<'a>(words: &'a [&'a str], n: usize) -> Vec<Vec<&'a str>> {
     }
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenize::WordTokenizer;
+
+    #[test]
+    fn test_zero_n_is_empty() {
+        assert!(word_ngrams(&["a", "b"], 0).is_empty());
+        assert!(word_ngrams_with("a b", 0, &WordTokenizer).is_empty());
+        assert_eq!(word_ngrams_with("a b", 2, &WordTokenizer), ["a b"]);
+    }
+}
@@ -0,0 +1,393 @@
+//! Offset conversion between bytes, chars, UTF-16 code units and grapheme clusters.
+//!
+//! Different consumers count positions differently:
+//! - Rust slicing wants **byte** offsets.
+//! - Most of this crate's historical APIs report **char** (Unicode scalar value) offsets.
+//! - JavaScript strings and the Language Server Protocol count **UTF-16 code units**.
+//! - UIs (cursor movement, truncation) want **grapheme clusters** (UAX #29).
+//!
+//! [`OffsetIndex`] is built once per text (\(O(n)\)) and then converts between all of
+//! these in \(O(\log n)\) (or \(O(1)\) when indexing by char).
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The unit an offset is counted in.
+///
+/// APIs that report positions ([`crate::tokenize::tokenize_with_offsets_in`],
+/// [`crate::FlashText::set_offset_unit`]) take this to decide what `start`/`end` mean.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OffsetUnit {
+    /// UTF-8 bytes; `&text[start..end]` is always valid.
+    Byte,
+    /// Unicode scalar values (`char`). This is the historical default.
+    #[default]
+    Char,
+    /// UTF-16 code units (JavaScript string indices, LSP positions).
+    Utf16,
+}
+
+impl OffsetUnit {
+    /// Length of `s` measured in this unit.
+    pub fn len_of(self, s: &str) -> usize {
+        match self {
+            OffsetUnit::Byte => s.len(),
+            OffsetUnit::Char => s.chars().count(),
+            OffsetUnit::Utf16 => s.chars().map(char::len_utf16).sum(),
+        }
+    }
+}
+
+/// Converts monotonically increasing byte offsets into `unit` offsets in a single pass.
+///
+/// Iterators like `unicode_word_indices()` and `AhoCorasick::find_iter()` yield byte
+/// offsets in increasing order. Converting each with `text[..b].chars().count()` is
+/// \(O(n^2)\) overall; this advances incrementally from the last boundary instead.
+/// For [`OffsetUnit::Byte`] no counting happens at all.
+pub(crate) struct OffsetCursor<'a> {
+    text: &'a str,
+    unit: OffsetUnit,
+    last_byte: usize,
+    last_pos: usize,
+}
+
+impl<'a> OffsetCursor<'a> {
+    pub(crate) fn new(text: &'a str, unit: OffsetUnit) -> Self {
+        Self {
+            text,
+            unit,
+            last_byte: 0,
+            last_pos: 0,
+        }
+    }
+
+    /// Offset of char boundary `byte` in `self.unit`.
+    pub(crate) fn advance_to(&mut self, byte: usize) -> usize {
+        if self.unit == OffsetUnit::Byte {
+            return byte;
+        }
+        if byte >= self.last_byte {
+            self.last_pos += self.unit.len_of(&self.text[self.last_byte..byte]);
+        } else {
+            // Defensive: callers are monotonic, but keep correctness if they are not.
+            self.last_pos = self.unit.len_of(&self.text[..byte]);
+        }
+        self.last_byte = byte;
+        self.last_pos
+    }
+}
+
+/// A half-open range `[start, end)` expressed in every offset unit at once.
+///
+/// Grapheme offsets are the smallest grapheme range that *covers* the span: if a span
+/// starts or ends inside a cluster (e.g. a keyword match on a base letter that is
+/// followed by a combining mark), the grapheme range is widened to whole clusters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span {
+    pub byte_start: usize,
+    pub byte_end: usize,
+    pub char_start: usize,
+    pub char_end: usize,
+    pub utf16_start: usize,
+    pub utf16_end: usize,
+    pub grapheme_start: usize,
+    pub grapheme_end: usize,
+}
+
+impl Span {
+    /// Byte range, suitable for slicing the original text.
+    pub fn byte_range(&self) -> std::ops::Range<usize> {
+        self.byte_start..self.byte_end
+    }
+
+    /// Char (Unicode scalar value) range.
+    pub fn char_range(&self) -> std::ops::Range<usize> {
+        self.char_start..self.char_end
+    }
+
+    /// UTF-16 code unit range (JavaScript / LSP positions).
+    pub fn utf16_range(&self) -> std::ops::Range<usize> {
+        self.utf16_start..self.utf16_end
+    }
+
+    /// Grapheme cluster range.
+    pub fn grapheme_range(&self) -> std::ops::Range<usize> {
+        self.grapheme_start..self.grapheme_end
+    }
+
+    /// Whether the span covers no text.
+    pub fn is_empty(&self) -> bool {
+        self.byte_start == self.byte_end
+    }
+}
+
+/// Precomputed position tables for one text.
+///
+/// All conversions return `None` when the input position is out of range or does not
+/// fall on a boundary of the source unit (e.g. a byte offset inside a multi-byte char,
+/// or a UTF-16 offset between the two halves of a surrogate pair).
+///
+/// The index does not borrow the text; it is only valid for the text it was built from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OffsetIndex {
+    /// `char_to_byte[i]` = byte offset of char `i`; has `n_chars + 1` entries.
+    char_to_byte: Vec<usize>,
+    /// `char_to_utf16[i]` = UTF-16 offset of char `i`; has `n_chars + 1` entries.
+    char_to_utf16: Vec<usize>,
+    /// `grapheme_to_char[g]` = char offset of cluster `g`; has `n_graphemes + 1` entries.
+    grapheme_to_char: Vec<usize>,
+}
+
+impl OffsetIndex {
+    /// Build the index for `text`.
+    pub fn new(text: &str) -> Self {
+        let mut char_to_byte = Vec::with_capacity(text.len() + 1);
+        let mut char_to_utf16 = Vec::with_capacity(text.len() + 1);
+        let mut utf16 = 0usize;
+        for (b, c) in text.char_indices() {
+            char_to_byte.push(b);
+            char_to_utf16.push(utf16);
+            utf16 += c.len_utf16();
+        }
+        char_to_byte.push(text.len());
+        char_to_utf16.push(utf16);
+
+        // Grapheme boundaries are always char boundaries, so walk both in lockstep.
+        let mut grapheme_to_char = Vec::new();
+        let mut ci = 0usize;
+        for (b, _) in text.grapheme_indices(true) {
+            while char_to_byte[ci] < b {
+                ci += 1;
+            }
+            grapheme_to_char.push(ci);
+        }
+        grapheme_to_char.push(char_to_byte.len() - 1);
+
+        Self {
+            char_to_byte,
+            char_to_utf16,
+            grapheme_to_char,
+        }
+    }
+
+    /// Length of the text in bytes.
+    pub fn len_bytes(&self) -> usize {
+        *self.char_to_byte.last().unwrap_or(&0)
+    }
+
+    /// Length of the text in chars.
+    pub fn len_chars(&self) -> usize {
+        self.char_to_byte.len() - 1
+    }
+
+    /// Length of the text in UTF-16 code units.
+    pub fn len_utf16(&self) -> usize {
+        *self.char_to_utf16.last().unwrap_or(&0)
+    }
+
+    /// Length of the text in grapheme clusters.
+    pub fn len_graphemes(&self) -> usize {
+        self.grapheme_to_char.len() - 1
+    }
+
+    /// Whether the indexed text is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len_bytes() == 0
+    }
+
+    pub fn char_to_byte(&self, char_idx: usize) -> Option<usize> {
+        self.char_to_byte.get(char_idx).copied()
+    }
+
+    pub fn byte_to_char(&self, byte_idx: usize) -> Option<usize> {
+        self.char_to_byte.binary_search(&byte_idx).ok()
+    }
+
+    pub fn char_to_utf16(&self, char_idx: usize) -> Option<usize> {
+        self.char_to_utf16.get(char_idx).copied()
+    }
+
+    pub fn utf16_to_char(&self, utf16_idx: usize) -> Option<usize> {
+        self.char_to_utf16.binary_search(&utf16_idx).ok()
+    }
+
+    pub fn byte_to_utf16(&self, byte_idx: usize) -> Option<usize> {
+        self.byte_to_char(byte_idx)
+            .and_then(|c| self.char_to_utf16(c))
+    }
+
+    pub fn utf16_to_byte(&self, utf16_idx: usize) -> Option<usize> {
+        self.utf16_to_char(utf16_idx)
+            .and_then(|c| self.char_to_byte(c))
+    }
+
+    pub fn grapheme_to_char(&self, grapheme_idx: usize) -> Option<usize> {
+        self.grapheme_to_char.get(grapheme_idx).copied()
+    }
+
+    /// Grapheme index of a char offset; `None` if the char is inside a cluster.
+    pub fn char_to_grapheme(&self, char_idx: usize) -> Option<usize> {
+        self.grapheme_to_char.binary_search(&char_idx).ok()
+    }
+
+    pub fn grapheme_to_byte(&self, grapheme_idx: usize) -> Option<usize> {
+        self.grapheme_to_char(grapheme_idx)
+            .and_then(|c| self.char_to_byte(c))
+    }
+
+    pub fn byte_to_grapheme(&self, byte_idx: usize) -> Option<usize> {
+        self.byte_to_char(byte_idx)
+            .and_then(|c| self.char_to_grapheme(c))
+    }
+
+    /// Index of the grapheme cluster that contains char `char_idx` (rounds down).
+    ///
+    /// `char_idx == len_chars()` maps to `len_graphemes()`.
+    pub fn char_to_grapheme_floor(&self, char_idx: usize) -> usize {
+        match self.grapheme_to_char.binary_search(&char_idx) {
+            Ok(g) => g,
+            Err(g) => g.saturating_sub(1),
+        }
+    }
+
+    /// Smallest grapheme index whose start is at or after char `char_idx` (rounds up).
+    pub fn char_to_grapheme_ceil(&self, char_idx: usize) -> usize {
+        match self.grapheme_to_char.binary_search(&char_idx) {
+            Ok(g) | Err(g) => g.min(self.len_graphemes()),
+        }
+    }
+
+    /// Convert `offset` from one unit to another.
+    ///
+    /// Returns `None` if `offset` is out of range or not on a boundary of `from`.
+    pub fn convert(&self, offset: usize, from: OffsetUnit, to: OffsetUnit) -> Option<usize> {
+        let c = match from {
+            OffsetUnit::Byte => self.byte_to_char(offset)?,
+            OffsetUnit::Char => (offset <= self.len_chars()).then_some(offset)?,
+            OffsetUnit::Utf16 => self.utf16_to_char(offset)?,
+        };
+        match to {
+            OffsetUnit::Byte => self.char_to_byte(c),
+            OffsetUnit::Char => Some(c),
+            OffsetUnit::Utf16 => self.char_to_utf16(c),
+        }
+    }
+
+    /// Build a [`Span`] from a range in `unit`.
+    pub fn span(&self, start: usize, end: usize, unit: OffsetUnit) -> Option<Span> {
+        match unit {
+            OffsetUnit::Byte => self.span_from_bytes(start, end),
+            OffsetUnit::Char => self.span_from_chars(start, end),
+            OffsetUnit::Utf16 => self.span_from_utf16(start, end),
+        }
+    }
+
+    /// Build a [`Span`] from a char range.
+    ///
+    /// Returns `None` if the range is reversed or out of bounds.
+    pub fn span_from_chars(&self, char_start: usize, char_end: usize) -> Option<Span> {
+        if char_start > char_end || char_end > self.len_chars() {
+            return None;
+        }
+        Some(Span {
+            byte_start: self.char_to_byte[char_start],
+            byte_end: self.char_to_byte[char_end],
+            char_start,
+            char_end,
+            utf16_start: self.char_to_utf16[char_start],
+            utf16_end: self.char_to_utf16[char_end],
+            grapheme_start: self.char_to_grapheme_floor(char_start),
+            grapheme_end: self.char_to_grapheme_ceil(char_end),
+        })
+    }
+
+    /// Build a [`Span`] from a byte range.
+    ///
+    /// Returns `None` if either end is not a char boundary, or the range is invalid.
+    pub fn span_from_bytes(&self, byte_start: usize, byte_end: usize) -> Option<Span> {
+        let s = self.byte_to_char(byte_start)?;
+        let e = self.byte_to_char(byte_end)?;
+        self.span_from_chars(s, e)
+    }
+
+    /// Build a [`Span`] from a UTF-16 range.
+    ///
+    /// Returns `None` if either end splits a surrogate pair, or the range is invalid.
+    pub fn span_from_utf16(&self, utf16_start: usize, utf16_end: usize) -> Option<Span> {
+        let s = self.utf16_to_char(utf16_start)?;
+        let e = self.utf16_to_char(utf16_end)?;
+        self.span_from_chars(s, e)
+    }
+
+    /// Build a [`Span`] from a grapheme range.
+    pub fn span_from_graphemes(&self, grapheme_start: usize, grapheme_end: usize) -> Option<Span> {
+        let s = self.grapheme_to_char(grapheme_start)?;
+        let e = self.grapheme_to_char(grapheme_end)?;
+        self.span_from_chars(s, e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offset_index_mixed_widths() {
+        // 'a' (1B, 1u16), 'é' (2B, 1u16), '東' (3B, 1u16), '😀' (4B, 2u16)
+        let text = "aé東😀";
+        let idx = OffsetIndex::new(text);
+        assert_eq!(idx.len_bytes(), 10);
+        assert_eq!(idx.len_chars(), 4);
+        assert_eq!(idx.len_utf16(), 5);
+        assert_eq!(idx.len_graphemes(), 4);
+
+        assert_eq!(idx.char_to_byte(3), Some(6));
+        assert_eq!(idx.byte_to_char(6), Some(3));
+        assert_eq!(idx.byte_to_char(2), None); // inside 'é'
+        assert_eq!(idx.byte_to_utf16(10), Some(5));
+        assert_eq!(idx.utf16_to_byte(4), None); // inside surrogate pair
+        assert_eq!(idx.utf16_to_byte(3), Some(6));
+    }
+
+    #[test]
+    fn test_offset_index_graphemes() {
+        // "e\u{301}" is one cluster; the family emoji is one cluster of 5 chars.
+        let text = "e\u{301}x👨\u{200D}👩\u{200D}👧";
+        let idx = OffsetIndex::new(text);
+        assert_eq!(idx.len_graphemes(), 3);
+        assert_eq!(idx.grapheme_to_char(1), Some(2));
+        assert_eq!(idx.grapheme_to_char(2), Some(3));
+        assert_eq!(idx.char_to_grapheme(1), None);
+
+        // A span over just the base letter widens to the whole cluster.
+        let span = idx.span_from_chars(0, 1).unwrap();
+        assert_eq!(span.grapheme_range(), 0..1);
+        assert_eq!(&text[span.byte_range()], "e");
+
+        let span = idx.span_from_graphemes(2, 3).unwrap();
+        assert_eq!(span.char_range(), 3..8);
+        assert_eq!(span.utf16_range(), 3..11);
+        assert_eq!(&text[span.byte_range()], "👨\u{200D}👩\u{200D}👧");
+    }
+
+    #[test]
+    fn test_offset_index_convert() {
+        let text = "a😀b";
+        let idx = OffsetIndex::new(text);
+        assert_eq!(idx.convert(2, OffsetUnit::Char, OffsetUnit::Byte), Some(5));
+        assert_eq!(idx.convert(5, OffsetUnit::Byte, OffsetUnit::Utf16), Some(3));
+        assert_eq!(idx.convert(2, OffsetUnit::Utf16, OffsetUnit::Char), None);
+        assert_eq!(idx.convert(4, OffsetUnit::Char, OffsetUnit::Byte), None);
+        assert_eq!(OffsetUnit::Utf16.len_of(text), 4);
+    }
+
+    #[test]
+    fn test_offset_index_empty() {
+        let idx = OffsetIndex::new("");
+        assert!(idx.is_empty());
+        assert_eq!(idx.len_graphemes(), 0);
+        assert_eq!(idx.span_from_bytes(0, 0), Some(Span::default()));
+        assert_eq!(idx.span_from_bytes(0, 1), None);
+    }
+}
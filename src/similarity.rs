@@ -5,8 +5,10 @@
 //!
 //! Notes:
 //! - Similarities are computed on **Unicode scalar values** (`char`), not bytes.
-//! - Tokenization for `word_jaccard` is whitespace-based for predictability.
+//! - Tokenization for `word_jaccard` is whitespace-based for predictability; use
+//!   `word_jaccard_with` to choose another [`Tokenizer`].
 
+use crate::tokenize::{Tokenizer, WhitespaceTokenizer};
 use std::collections::HashSet;
 
 /// Jaccard similarity for whitespace-delimited tokens.
@@ -15,11 +17,22 @@ use std::collections::HashSet;
 ///
 /// Case-insensitive: lowercases both inputs first.
 pub fn word_jaccard(a: &str, b: &str) -> f64 {
+    word_jaccard_with(a, b, &WhitespaceTokenizer)
+}
+
+/// Jaccard similarity for the tokens produced by `tokenizer`.
+///
+/// Case-insensitive: lowercases both inputs first.
+pub fn word_jaccard_with(a: &str, b: &str, tokenizer: &dyn Tokenizer) -> f64 {
     let a_lower = a.to_lowercase();
     let b_lower = b.to_lowercase();
 
-    let words_a: HashSet<&str> = a_lower.split_whitespace().collect();
-    let words_b: HashSet<&str> = b_lower.split_whitespace().collect();
+    let mut tokens = Vec::new();
+    tokenizer.tokenize_into(&a_lower, &mut tokens);
+    let words_a: HashSet<&str> = tokens.iter().map(|t| t.text).collect();
+    let mut tokens = Vec::new();
+    tokenizer.tokenize_into(&b_lower, &mut tokens);
+    let words_b: HashSet<&str> = tokens.iter().map(|t| t.text).collect();
 
     if words_a.is_empty() && words_b.is_empty() {
         return 1.0;
@@ -111,6 +124,16 @@ mod tests {
         assert_eq!(word_jaccard("", "x"), 0.0);
     }
 
+    #[test]
+    fn word_jaccard_with_tokenizer() {
+        use crate::tokenize::WordTokenizer;
+        assert_eq!(word_jaccard("Hello, world!", "hello world"), 0.0);
+        assert_eq!(
+            word_jaccard_with("Hello, world!", "hello world", &WordTokenizer),
+            1.0
+        );
+    }
+
     #[test]
     fn char_ngram_jaccard_bounds_and_identity() {
         let s = "François Müller";
@@ -0,0 +1,391 @@
+//! Snowball stemmers.
+//!
+//! Porter2 for English and the Snowball algorithms for French, German, Spanish,
+//! Italian, Portuguese, Dutch, Russian and Swedish, as published at
+//! <https://snowballstem.org/algorithms/>. Output matches the reference implementations
+//! for lowercase input.
+//!
+//! Stemming is lossy and language-specific: `"generously"` → `"generous"`,
+//! `"consolidated"` → `"consolid"`. Stems are index/search keys, not words; apply the
+//! same stemmer to queries and documents.
+//!
+//! Languages are selected with [`Language`], parsed from the same codes as
+//! [`crate::stopwords::get`] (`"en"`, `"english"`, `"de"`, …).
+
+mod dutch;
+mod english;
+mod french;
+mod german;
+mod italian;
+mod portuguese;
+mod russian;
+mod spanish;
+mod swedish;
+
+pub use crate::lang::Language;
+use crate::tokenize::Token;
+
+/// A stemmer for one language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stemmer {
+    lang: Language,
+}
+
+impl Stemmer {
+    pub fn new(lang: Language) -> Self {
+        Self { lang }
+    }
+
+    /// Stemmer for a language code (see [`Language::from_code`]).
+    pub fn for_code(code: &str) -> Option<Self> {
+        Language::from_code(code).map(Self::new)
+    }
+
+    pub fn language(&self) -> Language {
+        self.lang
+    }
+
+    /// Stem a single word. The word is lowercased first.
+    pub fn stem(&self, word: &str) -> String {
+        let mut w = Word::new(&word.to_lowercase());
+        match self.lang {
+            Language::English => english::stem(&mut w),
+            Language::French => french::stem(&mut w),
+            Language::German => german::stem(&mut w),
+            Language::Spanish => spanish::stem(&mut w),
+            Language::Italian => italian::stem(&mut w),
+            Language::Portuguese => portuguese::stem(&mut w),
+            Language::Dutch => dutch::stem(&mut w),
+            Language::Russian => russian::stem(&mut w),
+            Language::Swedish => swedish::stem(&mut w),
+        }
+        w.chars.into_iter().collect()
+    }
+
+    /// Stem every token, keeping its offsets (which still point at the original word).
+    ///
+    /// Tokens that are not words ([`TokenKind::is_word`]), such as numbers and URLs, are
+    /// copied unchanged.
+    ///
+    /// [`TokenKind::is_word`]: crate::tokenize::TokenKind::is_word
+    pub fn stem_tokens(&self, tokens: &[Token]) -> Vec<Token> {
+        tokens
+            .iter()
+            .map(|t| Token {
+                text: if t.kind.is_word() {
+                    self.stem(&t.text)
+                } else {
+                    t.text.clone()
+                },
+                start: t.start,
+                end: t.end,
+                kind: t.kind,
+            })
+            .collect()
+    }
+}
+
+/// Stem `word` with the stemmer for `lang`.
+pub fn stem(word: &str, lang: Language) -> String {
+    Stemmer::new(lang).stem(word)
+}
+
+/// A word being stemmed, with the suffix operations the Snowball algorithms are written
+/// in. Positions and lengths are in chars.
+struct Word {
+    chars: Vec<char>,
+}
+
+impl Word {
+    fn new(s: &str) -> Self {
+        Self {
+            chars: s.chars().collect(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.chars.len()
+    }
+
+    fn at(&self, i: usize) -> Option<char> {
+        self.chars.get(i).copied()
+    }
+
+    fn last(&self) -> Option<char> {
+        self.chars.last().copied()
+    }
+
+    fn is(&self, word: &str) -> bool {
+        self.chars.iter().copied().eq(word.chars())
+    }
+
+    /// Whether `suffix` occurs ending at char position `end`.
+    fn has_at(&self, end: usize, suffix: &str) -> bool {
+        let n = suffix.chars().count();
+        end <= self.len() && n <= end && self.chars[end - n..end].iter().copied().eq(suffix.chars())
+    }
+
+    fn ends_with(&self, suffix: &str) -> bool {
+        self.has_at(self.len(), suffix)
+    }
+
+    fn starts_with(&self, prefix: &str) -> bool {
+        let n = prefix.chars().count();
+        n <= self.len() && self.chars[..n].iter().copied().eq(prefix.chars())
+    }
+
+    /// The longest entry of `list` that the word ends with (Snowball `among`).
+    fn longest_suffix<'a>(&self, list: &[&'a str]) -> Option<&'a str> {
+        self.longest_suffix_in(list, 0)
+    }
+
+    /// Like [`Self::longest_suffix`], but only suffixes starting at or after `limit`.
+    fn longest_suffix_in<'a>(&self, list: &[&'a str], limit: usize) -> Option<&'a str> {
+        self.longest_suffix_at(self.len(), list, limit)
+    }
+
+    /// The longest entry of `list` ending at `end` and starting at or after `limit`.
+    fn longest_suffix_at<'a>(&self, end: usize, list: &[&'a str], limit: usize) -> Option<&'a str> {
+        list.iter()
+            .copied()
+            .filter(|s| {
+                let n = s.chars().count();
+                n <= end && end - n >= limit && self.has_at(end, s)
+            })
+            .max_by_key(|s| s.chars().count())
+    }
+
+    /// Like [`Self::longest_suffix`], for a table of `(suffix, value)` pairs.
+    fn longest_entry<'a, T>(&self, table: &'a [(&'a str, T)]) -> Option<&'a (&'a str, T)> {
+        table
+            .iter()
+            .filter(|(s, _)| self.ends_with(s))
+            .max_by_key(|(s, _)| s.chars().count())
+    }
+
+    /// Start position of `suffix`, which the word must end with.
+    fn start_of(&self, suffix: &str) -> usize {
+        self.len() - suffix.chars().count()
+    }
+
+    /// The char just before `suffix`, which the word must end with.
+    fn before(&self, suffix: &str) -> Option<char> {
+        self.start_of(suffix)
+            .checked_sub(1)
+            .and_then(|i| self.at(i))
+    }
+
+    fn remove(&mut self, suffix: &str) {
+        let start = self.start_of(suffix);
+        self.chars.truncate(start);
+    }
+
+    /// Remove `suffix` if the word ends with it and it starts at or after `region`.
+    fn remove_in(&mut self, suffix: &str, region: usize) -> bool {
+        let found = self.ends_with(suffix) && self.start_of(suffix) >= region;
+        if found {
+            self.remove(suffix);
+        }
+        found
+    }
+
+    fn replace(&mut self, suffix: &str, with: &str) {
+        self.remove(suffix);
+        self.chars.extend(with.chars());
+    }
+
+    fn push_str(&mut self, s: &str) {
+        self.chars.extend(s.chars());
+    }
+
+    fn map_chars(&mut self, f: impl Fn(char) -> char) {
+        for c in &mut self.chars {
+            *c = f(*c);
+        }
+    }
+
+    /// Uppercase each listed letter that sits between two vowels (`u` → `U`, …), left to
+    /// right, so that it no longer counts as a vowel.
+    fn mark_between_vowels(&mut self, pairs: &[(char, char)], is_vowel: fn(char) -> bool) {
+        for i in 1..self.len().saturating_sub(1) {
+            if !is_vowel(self.chars[i - 1]) || !is_vowel(self.chars[i + 1]) {
+                continue;
+            }
+            if let Some(&(_, to)) = pairs.iter().find(|&&(from, _)| from == self.chars[i]) {
+                self.chars[i] = to;
+            }
+        }
+    }
+
+    /// The position after the first non-vowel that follows a vowel, searching from
+    /// `from`; the word length if there is none. This is how R1 (from 0) and R2 (from
+    /// R1) are defined.
+    fn region_after(&self, from: usize, is_vowel: fn(char) -> bool) -> usize {
+        let mut i = from;
+        while i < self.len() && !is_vowel(self.chars[i]) {
+            i += 1;
+        }
+        while i < self.len() && is_vowel(self.chars[i]) {
+            i += 1;
+        }
+        (i + 1).min(self.len())
+    }
+
+    /// Standard R1 and R2.
+    fn regions(&self, is_vowel: fn(char) -> bool) -> (usize, usize) {
+        let r1 = self.region_after(0, is_vowel);
+        (r1, self.region_after(r1, is_vowel))
+    }
+
+    /// RV as defined for Spanish, Italian and Portuguese: if the second letter is a
+    /// consonant, the region after the next vowel; if the first two letters are vowels,
+    /// the region after the next consonant; otherwise the region after the third letter.
+    fn romance_rv(&self, is_vowel: fn(char) -> bool) -> usize {
+        let len = self.len();
+        if len < 2 {
+            return len;
+        }
+        let v = |i: usize| is_vowel(self.chars[i]);
+        let skip = |from: usize, want_vowel: bool| {
+            (from..len)
+                .find(|&i| v(i) == want_vowel)
+                .map_or(len, |i| i + 1)
+        };
+        match (v(0), v(1)) {
+            (_, false) => skip(2, true),
+            (true, true) => skip(2, false),
+            (false, true) => 3.min(len),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(lang: Language, pairs: &[(&str, &str)]) {
+        let stemmer = Stemmer::new(lang);
+        for (word, expected) in pairs {
+            assert_eq!(stemmer.stem(word), *expected, "{lang:?}: {word}");
+        }
+    }
+
+    #[test]
+    fn test_english() {
+        check(
+            Language::English,
+            &[
+                ("running", "run"),
+                ("generously", "generous"),
+                ("consolidated", "consolid"),
+                ("caresses", "caress"),
+                ("ponies", "poni"),
+                ("ties", "tie"),
+                ("hopping", "hop"),
+                ("hoping", "hope"),
+                ("relational", "relat"),
+                ("happily", "happili"),
+                ("skies", "sky"),
+                ("news", "news"),
+                ("generate", "generat"),
+                ("communism", "communism"),
+                ("Knightly", "knight"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_french() {
+        check(
+            Language::French,
+            &[
+                ("continuellement", "continuel"),
+                ("nationales", "national"),
+                ("chanteuses", "chanteux"),
+                ("finissons", "fin"),
+                ("mangeaient", "mang"),
+                ("prudemment", "prudent"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_german() {
+        check(
+            Language::German,
+            &[
+                ("häuser", "haus"),
+                ("freundlichkeit", "freundlich"),
+                ("aufeinanderfolgenden", "aufeinanderfolg"),
+                ("straße", "strass"),
+                ("kenntnisse", "kenntnis"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_spanish_italian_portuguese() {
+        check(
+            Language::Spanish,
+            &[
+                ("corriendo", "corr"),
+                ("nacionalidades", "nacional"),
+                ("diciéndoselo", "dic"),
+                ("rápidamente", "rapid"),
+            ],
+        );
+        check(
+            Language::Italian,
+            &[
+                ("abbandonata", "abbandon"),
+                ("mangiarlo", "mang"),
+                ("velocemente", "veloc"),
+            ],
+        );
+        check(
+            Language::Portuguese,
+            &[
+                ("nacionalidades", "nacional"),
+                ("corações", "coraçõ"),
+                ("rapidamente", "rapid"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_dutch_swedish_russian() {
+        check(
+            Language::Dutch,
+            &[
+                ("lichamelijk", "licham"),
+                ("kaas", "kas"),
+                ("opgaven", "opgav"),
+            ],
+        );
+        check(
+            Language::Swedish,
+            &[
+                ("kärleken", "kärlek"),
+                ("flickorna", "flick"),
+                ("upplöst", "upplös"),
+            ],
+        );
+        check(
+            Language::Russian,
+            &[
+                ("красивая", "красив"),
+                ("прочитавшись", "прочита"),
+                ("книгами", "книг"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_stem_tokens_keeps_offsets() {
+        let tokens = crate::tokenize::tokenize_with_offsets("Les chanteuses chantaient");
+        let stemmed = Stemmer::for_code("fr").unwrap().stem_tokens(&tokens);
+        let texts: Vec<&str> = stemmed.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(texts, ["le", "chanteux", "chant"]);
+        assert_eq!((stemmed[1].start, stemmed[1].end), (4, 14));
+    }
+}
@@ -0,0 +1,125 @@
+//! Snowball Dutch stemmer.
+
+use super::Word;
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y' | 'è')
+}
+
+struct Regions {
+    r1: usize,
+    r2: usize,
+}
+
+pub(super) fn stem(w: &mut Word) {
+    w.map_chars(|c| match c {
+        'á' | 'ä' => 'a',
+        'é' | 'ë' => 'e',
+        'í' | 'ï' => 'i',
+        'ó' | 'ö' => 'o',
+        'ú' | 'ü' => 'u',
+        c => c,
+    });
+    if w.at(0) == Some('y') {
+        w.chars[0] = 'Y';
+    }
+    for i in 1..w.len() {
+        if !is_vowel(w.chars[i - 1]) {
+            continue;
+        }
+        match w.chars[i] {
+            'i' if w.at(i + 1).is_some_and(is_vowel) => w.chars[i] = 'I',
+            'y' => w.chars[i] = 'Y',
+            _ => {}
+        }
+    }
+
+    let (r1, r2) = w.regions(is_vowel);
+    // R1 is adjusted so that the region before it contains at least 3 letters.
+    let r = Regions { r1: r1.max(3), r2 };
+
+    match w.longest_suffix(&["heden", "en", "ene", "s", "se"]) {
+        Some("heden") if w.start_of("heden") >= r.r1 => w.replace("heden", "heid"),
+        Some(s @ ("en" | "ene")) => en_ending(w, s, &r),
+        Some(s @ ("s" | "se"))
+            if w.start_of(s) >= r.r1 && w.before(s).is_some_and(|c| !is_vowel(c) && c != 'j') =>
+        {
+            w.remove(s)
+        }
+        _ => {}
+    }
+    let e_found = e_ending(w, &r);
+    if w.ends_with("heid") && w.start_of("heid") >= r.r2 && w.before("heid") != Some('c') {
+        w.remove("heid");
+        if w.ends_with("en") {
+            en_ending(w, "en", &r);
+        }
+    }
+    match w.longest_suffix(&["end", "ing", "ig", "lijk", "baar", "bar"]) {
+        Some(s @ ("end" | "ing")) if w.start_of(s) >= r.r2 => {
+            w.remove(s);
+            if w.ends_with("ig") && w.start_of("ig") >= r.r2 && w.before("ig") != Some('e') {
+                w.remove("ig");
+            } else {
+                undouble(w);
+            }
+        }
+        Some("ig") if w.start_of("ig") >= r.r2 && w.before("ig") != Some('e') => w.remove("ig"),
+        Some("lijk") if w.start_of("lijk") >= r.r2 => {
+            w.remove("lijk");
+            e_ending(w, &r);
+        }
+        Some("baar") if w.start_of("baar") >= r.r2 => w.remove("baar"),
+        Some("bar") if w.start_of("bar") >= r.r2 && e_found => w.remove("bar"),
+        _ => {}
+    }
+    undouble_vowel(w);
+
+    w.map_chars(|c| match c {
+        'Y' => 'y',
+        'I' => 'i',
+        c => c,
+    });
+}
+
+/// Remove a final `kk`, `dd` or `tt`'s last letter.
+fn undouble(w: &mut Word) {
+    if ["kk", "dd", "tt"].iter().any(|d| w.ends_with(d)) {
+        w.chars.pop();
+    }
+}
+
+/// Delete `suffix` if in R1, preceded by a non-vowel and not by `gem`; then undouble.
+fn en_ending(w: &mut Word, suffix: &str, r: &Regions) {
+    let start = w.start_of(suffix);
+    if start >= r.r1 && w.before(suffix).is_some_and(|c| !is_vowel(c)) && !w.has_at(start, "gem") {
+        w.remove(suffix);
+        undouble(w);
+    }
+}
+
+/// Delete a final `e` in R1 preceded by a non-vowel, then undouble. Returns whether the
+/// `e` was removed.
+fn e_ending(w: &mut Word, r: &Regions) -> bool {
+    if w.ends_with("e") && w.start_of("e") >= r.r1 && w.before("e").is_some_and(|c| !is_vowel(c)) {
+        w.remove("e");
+        undouble(w);
+        return true;
+    }
+    false
+}
+
+/// `kaas` → `kas`: a doubled vowel between a non-vowel and a final non-vowel other than
+/// `I` is reduced to one.
+fn undouble_vowel(w: &mut Word) {
+    let n = w.len();
+    if n < 4 {
+        return;
+    }
+    let last = w.chars[n - 1];
+    let doubled =
+        w.chars[n - 2] == w.chars[n - 3] && matches!(w.chars[n - 2], 'a' | 'e' | 'o' | 'u');
+    if !is_vowel(last) && last != 'I' && doubled && !is_vowel(w.chars[n - 4]) {
+        w.chars.remove(n - 2);
+    }
+}
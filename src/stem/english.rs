@@ -0,0 +1,253 @@
+//! Porter2 ("English") stemmer.
+
+use super::Word;
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y')
+}
+
+fn is_double(w: &Word) -> bool {
+    ["bb", "dd", "ff", "gg", "mm", "nn", "pp", "rr", "tt"]
+        .iter()
+        .any(|d| w.ends_with(d))
+}
+
+fn is_li_ending(c: char) -> bool {
+    matches!(c, 'c' | 'd' | 'e' | 'g' | 'h' | 'k' | 'm' | 'n' | 'r' | 't')
+}
+
+/// Whether the word up to `end` ends in a short syllable: non-vowel, vowel, non-vowel
+/// other than w/x/Y; or vowel, non-vowel at the very start of the word.
+fn ends_short_syllable(w: &Word, end: usize) -> bool {
+    let Some(last) = end.checked_sub(1).and_then(|i| w.at(i)) else {
+        return false;
+    };
+    if is_vowel(last) || end < 2 || !is_vowel(w.chars[end - 2]) {
+        return false;
+    }
+    end == 2 || (!is_vowel(w.chars[end - 3]) && !matches!(last, 'w' | 'x' | 'Y'))
+}
+
+fn has_vowel(w: &Word, end: usize) -> bool {
+    w.chars[..end].iter().any(|&c| is_vowel(c))
+}
+
+const EXCEPTIONS: &[(&str, &str)] = &[
+    ("skis", "ski"),
+    ("skies", "sky"),
+    ("dying", "die"),
+    ("lying", "lie"),
+    ("tying", "tie"),
+    ("idly", "idl"),
+    ("gently", "gentl"),
+    ("ugly", "ugli"),
+    ("early", "earli"),
+    ("only", "onli"),
+    ("singly", "singl"),
+    ("sky", "sky"),
+    ("news", "news"),
+    ("howe", "howe"),
+    ("atlas", "atlas"),
+    ("cosmos", "cosmos"),
+    ("bias", "bias"),
+    ("andes", "andes"),
+];
+
+/// Words left alone once step 1a has run.
+const INVARIANT_AFTER_1A: &[&str] = &[
+    "inning", "outing", "canning", "herring", "earring", "proceed", "exceed", "succeed",
+];
+
+pub(super) fn stem(w: &mut Word) {
+    if let Some(&(_, out)) = EXCEPTIONS.iter().find(|(word, _)| w.is(word)) {
+        *w = Word::new(out);
+        return;
+    }
+    if w.len() < 3 {
+        return;
+    }
+
+    // Prelude.
+    if w.at(0) == Some('\'') {
+        w.chars.remove(0);
+    }
+    if w.at(0) == Some('y') {
+        w.chars[0] = 'Y';
+    }
+    for i in 1..w.len() {
+        if w.chars[i] == 'y' && is_vowel(w.chars[i - 1]) {
+            w.chars[i] = 'Y';
+        }
+    }
+
+    let r1 = ["gener", "commun", "arsen"]
+        .iter()
+        .find(|p| w.starts_with(p))
+        .map_or_else(|| w.region_after(0, is_vowel), |p| p.len());
+    let r2 = w.region_after(r1, is_vowel);
+
+    step_0(w);
+    step_1a(w);
+    if INVARIANT_AFTER_1A.iter().any(|word| w.is(word)) {
+        return;
+    }
+    step_1b(w, r1);
+    step_1c(w);
+    step_2(w, r1);
+    step_3(w, r1, r2);
+    step_4(w, r2);
+    step_5(w, r1, r2);
+
+    w.map_chars(|c| if c == 'Y' { 'y' } else { c });
+}
+
+fn step_0(w: &mut Word) {
+    if let Some(s) = w.longest_suffix(&["'s'", "'s", "'"]) {
+        w.remove(s);
+    }
+}
+
+fn step_1a(w: &mut Word) {
+    match w.longest_suffix(&["sses", "ied", "ies", "s", "us", "ss"]) {
+        Some("sses") => w.replace("sses", "ss"),
+        Some(s @ ("ied" | "ies")) => {
+            let with = if w.start_of(s) > 1 { "i" } else { "ie" };
+            w.replace(s, with);
+        }
+        Some("s") => {
+            let start = w.start_of("s");
+            if start >= 2 && has_vowel(w, start - 1) {
+                w.remove("s");
+            }
+        }
+        _ => {}
+    }
+}
+
+fn step_1b(w: &mut Word, r1: usize) {
+    let Some(s) = w.longest_suffix(&["eed", "eedly", "ed", "edly", "ing", "ingly"]) else {
+        return;
+    };
+    if matches!(s, "eed" | "eedly") {
+        if w.start_of(s) >= r1 {
+            w.replace(s, "ee");
+        }
+        return;
+    }
+    if !has_vowel(w, w.start_of(s)) {
+        return;
+    }
+    w.remove(s);
+    if w.ends_with("at") || w.ends_with("bl") || w.ends_with("iz") {
+        w.push_str("e");
+    } else if is_double(w) {
+        w.chars.pop();
+    } else if r1 == w.len() && ends_short_syllable(w, w.len()) {
+        w.push_str("e");
+    }
+}
+
+fn step_1c(w: &mut Word) {
+    if matches!(w.last(), Some('y' | 'Y')) {
+        let n = w.len();
+        if n > 2 && !is_vowel(w.chars[n - 2]) {
+            w.chars[n - 1] = 'i';
+        }
+    }
+}
+
+const STEP_2: &[(&str, &str)] = &[
+    ("tional", "tion"),
+    ("enci", "ence"),
+    ("anci", "ance"),
+    ("abli", "able"),
+    ("entli", "ent"),
+    ("izer", "ize"),
+    ("ization", "ize"),
+    ("ational", "ate"),
+    ("ation", "ate"),
+    ("ator", "ate"),
+    ("alism", "al"),
+    ("aliti", "al"),
+    ("alli", "al"),
+    ("fulness", "ful"),
+    ("ousli", "ous"),
+    ("ousness", "ous"),
+    ("iveness", "ive"),
+    ("iviti", "ive"),
+    ("biliti", "ble"),
+    ("bli", "ble"),
+    ("ogi", "og"),
+    ("fulli", "ful"),
+    ("lessli", "less"),
+    ("li", ""),
+];
+
+fn step_2(w: &mut Word, r1: usize) {
+    let Some(&(s, with)) = w.longest_entry(STEP_2) else {
+        return;
+    };
+    if w.start_of(s) < r1 {
+        return;
+    }
+    match s {
+        "ogi" if w.before(s) != Some('l') => {}
+        "li" if !w.before(s).is_some_and(is_li_ending) => {}
+        _ => w.replace(s, with),
+    }
+}
+
+const STEP_3: &[(&str, &str)] = &[
+    ("tional", "tion"),
+    ("ational", "ate"),
+    ("alize", "al"),
+    ("icate", "ic"),
+    ("iciti", "ic"),
+    ("ical", "ic"),
+    ("ful", ""),
+    ("ness", ""),
+    ("ative", ""),
+];
+
+fn step_3(w: &mut Word, r1: usize, r2: usize) {
+    let Some(&(s, with)) = w.longest_entry(STEP_3) else {
+        return;
+    };
+    let start = w.start_of(s);
+    if start >= r1 && (s != "ative" || start >= r2) {
+        w.replace(s, with);
+    }
+}
+
+fn step_4(w: &mut Word, r2: usize) {
+    let Some(s) = w.longest_suffix(&[
+        "al", "ance", "ence", "er", "ic", "able", "ible", "ant", "ement", "ment", "ent", "ism",
+        "ate", "iti", "ous", "ive", "ize", "ion",
+    ]) else {
+        return;
+    };
+    if w.start_of(s) < r2 {
+        return;
+    }
+    if s != "ion" || matches!(w.before(s), Some('s' | 't')) {
+        w.remove(s);
+    }
+}
+
+fn step_5(w: &mut Word, r1: usize, r2: usize) {
+    match w.last() {
+        Some('e') => {
+            let start = w.len() - 1;
+            if start >= r2 || (start >= r1 && !ends_short_syllable(w, start)) {
+                w.chars.pop();
+            }
+        }
+        Some('l') => {
+            let start = w.len() - 1;
+            if start >= r2 && w.before("l") == Some('l') {
+                w.chars.pop();
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,354 @@
+//! Snowball French stemmer.
+
+use super::Word;
+
+fn is_vowel(c: char) -> bool {
+    matches!(
+        c,
+        'a' | 'e'
+            | 'i'
+            | 'o'
+            | 'u'
+            | 'y'
+            | 'â'
+            | 'à'
+            | 'ë'
+            | 'é'
+            | 'ê'
+            | 'è'
+            | 'ï'
+            | 'î'
+            | 'ô'
+            | 'û'
+            | 'ù'
+    )
+}
+
+struct Regions {
+    rv: usize,
+    r1: usize,
+    r2: usize,
+}
+
+pub(super) fn stem(w: &mut Word) {
+    prelude(w);
+    let rv = rv(w);
+    let (r1, r2) = w.regions(is_vowel);
+    let r = Regions { rv, r1, r2 };
+
+    let changed = match standard_suffix(w, &r) {
+        Step::Done => true,
+        Step::Continue => i_verb_suffix(w, &r) || verb_suffix(w, &r),
+    };
+    if changed {
+        match w.last() {
+            Some('Y') => w.replace("Y", "i"),
+            Some('ç') => w.replace("ç", "c"),
+            _ => {}
+        }
+    } else {
+        residual_suffix(w, &r);
+    }
+
+    undouble(w);
+    unaccent(w);
+    w.map_chars(|c| match c {
+        'I' => 'i',
+        'U' => 'u',
+        'Y' => 'y',
+        c => c,
+    });
+}
+
+/// Mark u and i between vowels, y next to a vowel, and u after q as consonants.
+fn prelude(w: &mut Word) {
+    let mut p = 0;
+    while p + 1 < w.len() {
+        let (cur, next) = (w.chars[p], w.chars[p + 1]);
+        let after = w.at(p + 2);
+        if is_vowel(cur) && next == 'u' && after.is_some_and(is_vowel) {
+            w.chars[p + 1] = 'U';
+        } else if is_vowel(cur) && next == 'i' && after.is_some_and(is_vowel) {
+            w.chars[p + 1] = 'I';
+        } else if is_vowel(cur) && next == 'y' {
+            w.chars[p + 1] = 'Y';
+        } else if cur == 'y' && is_vowel(next) {
+            w.chars[p] = 'Y';
+        } else if cur == 'q' && next == 'u' {
+            w.chars[p + 1] = 'U';
+        }
+        p += 1;
+    }
+}
+
+fn rv(w: &Word) -> usize {
+    let len = w.len();
+    if len >= 2 && is_vowel(w.chars[0]) && is_vowel(w.chars[1]) {
+        return 3.min(len);
+    }
+    if ["par", "col", "tap"].iter().any(|p| w.starts_with(p)) {
+        return 3;
+    }
+    (1..len)
+        .find(|&i| is_vowel(w.chars[i]))
+        .map_or(len, |i| i + 1)
+}
+
+/// Outcome of step 1: either it removed an ending and the verb steps are skipped, or
+/// they still run (nothing matched, or one of the `-ment` endings was handled).
+enum Step {
+    Done,
+    Continue,
+}
+
+fn standard_suffix(w: &mut Word, r: &Regions) -> Step {
+    let Some(s) = w.longest_suffix(&[
+        "ance",
+        "iqUe",
+        "isme",
+        "able",
+        "iste",
+        "eux",
+        "ances",
+        "iqUes",
+        "ismes",
+        "ables",
+        "istes",
+        "atrice",
+        "ateur",
+        "ation",
+        "atrices",
+        "ateurs",
+        "ations",
+        "logie",
+        "logies",
+        "usion",
+        "ution",
+        "usions",
+        "utions",
+        "ence",
+        "ences",
+        "ement",
+        "ements",
+        "ité",
+        "ités",
+        "if",
+        "ive",
+        "ifs",
+        "ives",
+        "eaux",
+        "aux",
+        "euse",
+        "euses",
+        "issement",
+        "issements",
+        "amment",
+        "emment",
+        "ment",
+        "ments",
+    ]) else {
+        return Step::Continue;
+    };
+    let start = w.start_of(s);
+    let in_r1 = start >= r.r1;
+    let in_r2 = start >= r.r2;
+    let in_rv = start >= r.rv;
+    match s {
+        "ance" | "iqUe" | "isme" | "able" | "iste" | "eux" | "ances" | "iqUes" | "ismes"
+        | "ables" | "istes"
+            if in_r2 =>
+        {
+            w.remove(s)
+        }
+        "atrice" | "ateur" | "ation" | "atrices" | "ateurs" | "ations" if in_r2 => {
+            w.remove(s);
+            if w.ends_with("ic") {
+                if w.start_of("ic") >= r.r2 {
+                    w.remove("ic");
+                } else {
+                    w.replace("ic", "iqU");
+                }
+            }
+        }
+        "logie" | "logies" if in_r2 => w.replace(s, "log"),
+        "usion" | "ution" | "usions" | "utions" if in_r2 => w.replace(s, "u"),
+        "ence" | "ences" if in_r2 => w.replace(s, "ent"),
+        "ement" | "ements" if in_rv => {
+            w.remove(s);
+            match w.longest_suffix(&["iv", "eus", "abl", "iqU", "ièr", "Ièr"]) {
+                Some("iv") if w.start_of("iv") >= r.r2 => {
+                    w.remove("iv");
+                    if w.ends_with("at") && w.start_of("at") >= r.r2 {
+                        w.remove("at");
+                    }
+                }
+                Some("eus") => {
+                    if w.start_of("eus") >= r.r2 {
+                        w.remove("eus");
+                    } else if w.start_of("eus") >= r.r1 {
+                        w.replace("eus", "eux");
+                    }
+                }
+                Some(t @ ("abl" | "iqU")) if w.start_of(t) >= r.r2 => w.remove(t),
+                Some(t @ ("ièr" | "Ièr")) if w.start_of(t) >= r.rv => w.replace(t, "i"),
+                _ => {}
+            }
+        }
+        "ité" | "ités" if in_r2 => {
+            w.remove(s);
+            match w.longest_suffix(&["abil", "ic", "iv"]) {
+                Some("abil") => {
+                    if w.start_of("abil") >= r.r2 {
+                        w.remove("abil");
+                    } else {
+                        w.replace("abil", "abl");
+                    }
+                }
+                Some("ic") => {
+                    if w.start_of("ic") >= r.r2 {
+                        w.remove("ic");
+                    } else {
+                        w.replace("ic", "iqU");
+                    }
+                }
+                Some("iv") if w.start_of("iv") >= r.r2 => w.remove("iv"),
+                _ => {}
+            }
+        }
+        "if" | "ive" | "ifs" | "ives" if in_r2 => {
+            w.remove(s);
+            if w.ends_with("at") && w.start_of("at") >= r.r2 {
+                w.remove("at");
+                if w.ends_with("ic") {
+                    if w.start_of("ic") >= r.r2 {
+                        w.remove("ic");
+                    } else {
+                        w.replace("ic", "iqU");
+                    }
+                }
+            }
+        }
+        "eaux" => w.replace(s, "eau"),
+        "aux" if in_r1 => w.replace(s, "al"),
+        "euse" | "euses" if in_r2 => w.remove(s),
+        "euse" | "euses" if in_r1 => w.replace(s, "eux"),
+        "issement" | "issements" if in_r1 && w.before(s).is_some_and(|c| !is_vowel(c)) => {
+            w.remove(s)
+        }
+        "amment" => {
+            if in_rv {
+                w.replace(s, "ant");
+            }
+            return Step::Continue;
+        }
+        "emment" => {
+            if in_rv {
+                w.replace(s, "ent");
+            }
+            return Step::Continue;
+        }
+        "ment" | "ments" => {
+            if start > r.rv && w.before(s).is_some_and(is_vowel) {
+                w.remove(s);
+            }
+            return Step::Continue;
+        }
+        _ => return Step::Continue,
+    }
+    Step::Done
+}
+
+fn i_verb_suffix(w: &mut Word, r: &Regions) -> bool {
+    let Some(s) = w.longest_suffix_in(
+        &[
+            "îmes", "ît", "îtes", "i", "ie", "ies", "ir", "ira", "irai", "iraIent", "irais",
+            "irait", "iras", "irent", "irez", "iriez", "irions", "irons", "iront", "is",
+            "issaIent", "issais", "issait", "issant", "issante", "issantes", "issants", "isse",
+            "issent", "isses", "issez", "issiez", "issions", "issons", "it",
+        ],
+        r.rv,
+    ) else {
+        return false;
+    };
+    let start = w.start_of(s);
+    if start > r.rv && w.before(s).is_some_and(|c| !is_vowel(c)) {
+        w.remove(s);
+        return true;
+    }
+    false
+}
+
+fn verb_suffix(w: &mut Word, r: &Regions) -> bool {
+    let Some(s) = w.longest_suffix_in(
+        &[
+            "ions", "é", "ée", "ées", "és", "èrent", "er", "era", "erai", "eraIent", "erais",
+            "erait", "eras", "erez", "eriez", "erions", "erons", "eront", "ez", "iez", "âmes",
+            "ât", "âtes", "a", "ai", "aIent", "ais", "ait", "ant", "ante", "antes", "ants", "as",
+            "asse", "assent", "asses", "assiez", "assions",
+        ],
+        r.rv,
+    ) else {
+        return false;
+    };
+    match s {
+        "ions" => {
+            if w.start_of(s) < r.r2 {
+                return false;
+            }
+            w.remove(s);
+        }
+        "âmes" | "ât" | "âtes" | "a" | "ai" | "aIent" | "ais" | "ait" | "ant" | "ante"
+        | "antes" | "ants" | "as" | "asse" | "assent" | "asses" | "assiez" | "assions" => {
+            w.remove(s);
+            if w.ends_with("e") && w.start_of("e") >= r.rv {
+                w.remove("e");
+            }
+        }
+        _ => w.remove(s),
+    }
+    true
+}
+
+fn residual_suffix(w: &mut Word, r: &Regions) {
+    if w.ends_with("s")
+        && w.before("s")
+            .is_some_and(|c| !matches!(c, 'a' | 'i' | 'o' | 'u' | 'è' | 's'))
+    {
+        w.remove("s");
+    }
+    if w.len() < r.rv {
+        return;
+    }
+    match w.longest_suffix_in(&["ion", "ier", "ière", "Ier", "Ière", "e", "ë"], r.rv) {
+        Some("ion") => {
+            let start = w.start_of("ion");
+            if start >= r.r2 && start > r.rv && matches!(w.before("ion"), Some('s' | 't')) {
+                w.remove("ion");
+            }
+        }
+        Some(s @ ("ier" | "ière" | "Ier" | "Ière")) => w.replace(s, "i"),
+        Some("e") => w.remove("e"),
+        Some("ë") if w.has_at(w.len() - 1, "gu") && w.start_of("ë") >= r.rv + 2 => w.remove("ë"),
+        _ => {}
+    }
+}
+
+fn undouble(w: &mut Word) {
+    if ["enn", "onn", "ett", "ell", "eill"]
+        .iter()
+        .any(|s| w.ends_with(s))
+    {
+        w.chars.pop();
+    }
+}
+
+/// é or è followed by one or more non-vowels at the end of the word becomes e.
+fn unaccent(w: &mut Word) {
+    let mut i = w.len();
+    while i > 0 && !is_vowel(w.chars[i - 1]) {
+        i -= 1;
+    }
+    if i < w.len() && i > 0 && matches!(w.chars[i - 1], 'é' | 'è') {
+        w.chars[i - 1] = 'e';
+    }
+}
@@ -0,0 +1,117 @@
+//! Snowball German stemmer.
+
+use super::Word;
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y' | 'ä' | 'ö' | 'ü')
+}
+
+fn is_s_ending(c: char) -> bool {
+    matches!(
+        c,
+        'b' | 'd' | 'f' | 'g' | 'h' | 'k' | 'l' | 'm' | 'n' | 'r' | 't'
+    )
+}
+
+fn is_st_ending(c: char) -> bool {
+    c != 'r' && is_s_ending(c)
+}
+
+pub(super) fn stem(w: &mut Word) {
+    if w.chars.contains(&'ß') {
+        let s: String = w.chars.iter().collect();
+        *w = Word::new(&s.replace('ß', "ss"));
+    }
+    w.mark_between_vowels(&[('u', 'U'), ('y', 'Y')], is_vowel);
+
+    let (r1, r2) = w.regions(is_vowel);
+    // R1 is adjusted so that the region before it contains at least 3 letters.
+    let r1 = if w.len() >= 3 { r1.max(3) } else { r1 };
+
+    step_1(w, r1);
+    step_2(w, r1);
+    step_3(w, r1, r2);
+
+    w.map_chars(|c| match c {
+        'U' | 'ü' => 'u',
+        'Y' => 'y',
+        'ä' => 'a',
+        'ö' => 'o',
+        c => c,
+    });
+}
+
+fn step_1(w: &mut Word, r1: usize) {
+    let Some(s) = w.longest_suffix(&["e", "em", "en", "ern", "er", "s", "es"]) else {
+        return;
+    };
+    if w.start_of(s) < r1 {
+        return;
+    }
+    match s {
+        "em" | "ern" | "er" => w.remove(s),
+        "e" | "en" | "es" => {
+            w.remove(s);
+            if w.ends_with("niss") {
+                w.chars.pop();
+            }
+        }
+        _ => {
+            if w.before(s).is_some_and(is_s_ending) {
+                w.remove(s);
+            }
+        }
+    }
+}
+
+fn step_2(w: &mut Word, r1: usize) {
+    let Some(s) = w.longest_suffix(&["en", "er", "st", "est"]) else {
+        return;
+    };
+    let start = w.start_of(s);
+    if start < r1 {
+        return;
+    }
+    if s != "st" || (start >= 4 && w.before(s).is_some_and(is_st_ending)) {
+        w.remove(s);
+    }
+}
+
+fn step_3(w: &mut Word, r1: usize, r2: usize) {
+    let Some(s) = w.longest_suffix(&["end", "ig", "ung", "lich", "isch", "ik", "heit", "keit"])
+    else {
+        return;
+    };
+    if w.start_of(s) < r2 {
+        return;
+    }
+    match s {
+        "end" | "ung" => {
+            w.remove(s);
+            if w.ends_with("ig") && w.start_of("ig") >= r2 && w.before("ig") != Some('e') {
+                w.remove("ig");
+            }
+        }
+        "ig" | "ik" | "isch" => {
+            if w.before(s) != Some('e') {
+                w.remove(s);
+            }
+        }
+        "lich" | "heit" => {
+            w.remove(s);
+            if let Some(t) = w.longest_suffix(&["er", "en"]) {
+                if w.start_of(t) >= r1 {
+                    w.remove(t);
+                }
+            }
+        }
+        _ => {
+            w.remove(s);
+            if let Some(t) = w.longest_suffix(&["lich", "ig"]) {
+                if w.start_of(t) >= r2 {
+                    w.remove(t);
+                }
+            }
+        }
+    }
+}
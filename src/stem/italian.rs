@@ -0,0 +1,141 @@
+//! Snowball Italian stemmer.
+
+use super::Word;
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'à' | 'è' | 'ì' | 'ò' | 'ù')
+}
+
+pub(super) fn stem(w: &mut Word) {
+    w.map_chars(|c| match c {
+        'á' => 'à',
+        'é' => 'è',
+        'í' => 'ì',
+        'ó' => 'ò',
+        'ú' => 'ù',
+        c => c,
+    });
+    for i in 1..w.len() {
+        if w.chars[i - 1] == 'q' && w.chars[i] == 'u' {
+            w.chars[i] = 'U';
+        }
+    }
+    w.mark_between_vowels(&[('u', 'U'), ('i', 'I')], is_vowel);
+
+    let rv = w.romance_rv(is_vowel);
+    let (r1, r2) = w.regions(is_vowel);
+
+    attached_pronoun(w, rv);
+    if !standard_suffix(w, rv, r1, r2) {
+        verb_suffix(w, rv);
+    }
+    vowel_suffix(w, rv);
+
+    w.map_chars(|c| match c {
+        'I' => 'i',
+        'U' => 'u',
+        c => c,
+    });
+}
+
+fn attached_pronoun(w: &mut Word, rv: usize) {
+    let Some(pronoun) = w.longest_suffix(&[
+        "ci", "gli", "la", "le", "li", "lo", "mi", "ne", "si", "ti", "vi", "sene", "gliela",
+        "gliele", "glieli", "glielo", "gliene", "mela", "mele", "meli", "melo", "mene", "tela",
+        "tele", "teli", "telo", "tene", "cela", "cele", "celi", "celo", "cene", "vela", "vele",
+        "veli", "velo", "vene",
+    ]) else {
+        return;
+    };
+    let end = w.start_of(pronoun);
+    match w.longest_suffix_at(end, &["ando", "endo", "ar", "er", "ir"], rv) {
+        Some("ando" | "endo") => w.remove(pronoun),
+        Some(_) => w.replace(pronoun, "e"),
+        None => {}
+    }
+}
+
+fn standard_suffix(w: &mut Word, rv: usize, r1: usize, r2: usize) -> bool {
+    let Some(s) = w.longest_suffix(&[
+        "anza", "anze", "ico", "ici", "ica", "ice", "iche", "ichi", "ismo", "ismi", "abile",
+        "abili", "ibile", "ibili", "ista", "iste", "isti", "istà", "istè", "istì", "oso", "osi",
+        "osa", "ose", "mente", "atrice", "atrici", "ante", "anti", "azione", "azioni", "atore",
+        "atori", "logia", "logie", "uzione", "uzioni", "usione", "usioni", "enza", "enze",
+        "amento", "amenti", "imento", "imenti", "amente", "ità", "ivo", "ivi", "iva", "ive",
+    ]) else {
+        return false;
+    };
+    let region = match s {
+        "amento" | "amenti" | "imento" | "imenti" => rv,
+        "amente" => r1,
+        _ => r2,
+    };
+    if w.start_of(s) < region {
+        return false;
+    }
+    w.remove(s);
+    match s {
+        "azione" | "azioni" | "atore" | "atori" => {
+            w.remove_in("ic", r2);
+        }
+        "logia" | "logie" => w.push_str("log"),
+        "uzione" | "uzioni" | "usione" | "usioni" => w.push_str("u"),
+        "enza" | "enze" => w.push_str("ente"),
+        "amente" => match w.longest_suffix(&["iv", "os", "ic", "abil"]) {
+            Some("iv") if w.start_of("iv") >= r2 => {
+                w.remove("iv");
+                w.remove_in("at", r2);
+            }
+            Some(t) => {
+                w.remove_in(t, r2);
+            }
+            None => {}
+        },
+        "ità" => {
+            if let Some(t) = w.longest_suffix(&["abil", "ic", "iv"]) {
+                w.remove_in(t, r2);
+            }
+        }
+        "ivo" | "ivi" | "iva" | "ive" if w.ends_with("at") && w.start_of("at") >= r2 => {
+            w.remove("at");
+            w.remove_in("ic", r2);
+        }
+        _ => {}
+    }
+    true
+}
+
+fn verb_suffix(w: &mut Word, rv: usize) {
+    if let Some(s) = w.longest_suffix_in(
+        &[
+            "ammo", "ando", "ano", "are", "arono", "asse", "assero", "assi", "assimo", "ata",
+            "ate", "ati", "ato", "ava", "avamo", "avano", "avate", "avi", "avo", "emmo", "enda",
+            "ende", "endi", "endo", "erà", "erai", "eranno", "ere", "erebbe", "erebbero", "erei",
+            "eremmo", "eremo", "ereste", "eresti", "erete", "erò", "erono", "essero", "ete", "eva",
+            "evamo", "evano", "evate", "evi", "evo", "Yamo", "iamo", "immo", "irà", "irai",
+            "iranno", "ire", "irebbe", "irebbero", "irei", "iremmo", "iremo", "ireste", "iresti",
+            "irete", "irò", "irono", "isca", "iscano", "isce", "isci", "isco", "iscono", "issero",
+            "ita", "ite", "iti", "ito", "iva", "ivamo", "ivano", "ivate", "ivi", "ivo", "ono",
+            "uta", "ute", "uti", "uto", "ar", "ir",
+        ],
+        rv,
+    ) {
+        w.remove(s);
+    }
+}
+
+fn vowel_suffix(w: &mut Word, rv: usize) {
+    let n = w.len();
+    if n > rv
+        && matches!(
+            w.last(),
+            Some('a' | 'e' | 'i' | 'o' | 'à' | 'è' | 'ì' | 'ò')
+        )
+    {
+        w.chars.pop();
+        w.remove_in("i", rv);
+    }
+    if w.ends_with("h") && matches!(w.before("h"), Some('c' | 'g')) && w.len() >= rv + 2 {
+        w.chars.pop();
+    }
+}
@@ -0,0 +1,137 @@
+//! Snowball Portuguese stemmer.
+
+use super::Word;
+
+fn is_vowel(c: char) -> bool {
+    matches!(
+        c,
+        'a' | 'e' | 'i' | 'o' | 'u' | 'á' | 'é' | 'í' | 'ó' | 'ú' | 'â' | 'ê' | 'ô'
+    )
+}
+
+pub(super) fn stem(w: &mut Word) {
+    // Nasal vowels are written as a vowel plus `~` while stemming, so that `ã` and `õ`
+    // count as a vowel followed by a consonant.
+    if w.chars.iter().any(|&c| matches!(c, 'ã' | 'õ')) {
+        let s: String = w.chars.iter().collect();
+        *w = Word::new(&s.replace('ã', "a~").replace('õ', "o~"));
+    }
+
+    let rv = w.romance_rv(is_vowel);
+    let (r1, r2) = w.regions(is_vowel);
+
+    if standard_suffix(w, rv, r1, r2) || verb_suffix(w, rv) {
+        if w.ends_with("ci") && w.start_of("i") >= rv {
+            w.chars.pop();
+        }
+    } else {
+        residual_suffix(w, rv);
+    }
+    residual_form(w, rv);
+
+    if w.chars.contains(&'~') {
+        let s: String = w.chars.iter().collect();
+        *w = Word::new(&s.replace("a~", "ã").replace("o~", "õ"));
+    }
+}
+
+fn standard_suffix(w: &mut Word, rv: usize, r1: usize, r2: usize) -> bool {
+    let Some(s) = w.longest_suffix(&[
+        "eza", "ezas", "ico", "ica", "icos", "icas", "ismo", "ismos", "ável", "ível", "ista",
+        "istas", "oso", "osa", "osos", "osas", "amento", "amentos", "imento", "imentos", "adora",
+        "ador", "aça~o", "adoras", "adores", "aço~es", "ante", "antes", "ância", "logia", "logias",
+        "uça~o", "uço~es", "ência", "ências", "amente", "mente", "idade", "idades", "iva", "ivo",
+        "ivas", "ivos", "ira", "iras",
+    ]) else {
+        return false;
+    };
+    let start = w.start_of(s);
+    match s {
+        "amente" if start < r1 => return false,
+        "ira" | "iras" => {
+            if start < rv || w.before(s) != Some('e') {
+                return false;
+            }
+            w.replace(s, "ir");
+            return true;
+        }
+        "amente" => {}
+        _ if start < r2 => return false,
+        _ => {}
+    }
+    w.remove(s);
+    match s {
+        "logia" | "logias" => w.push_str("log"),
+        "uça~o" | "uço~es" => w.push_str("u"),
+        "ência" | "ências" => w.push_str("ente"),
+        "amente" => match w.longest_suffix(&["iv", "os", "ic", "ad"]) {
+            Some("iv") if w.start_of("iv") >= r2 => {
+                w.remove("iv");
+                w.remove_in("at", r2);
+            }
+            Some(t) => {
+                w.remove_in(t, r2);
+            }
+            None => {}
+        },
+        "mente" => {
+            if let Some(t) = w.longest_suffix(&["ante", "avel", "ível"]) {
+                w.remove_in(t, r2);
+            }
+        }
+        "idade" | "idades" => {
+            if let Some(t) = w.longest_suffix(&["abil", "ic", "iv"]) {
+                w.remove_in(t, r2);
+            }
+        }
+        "iva" | "ivo" | "ivas" | "ivos" => {
+            w.remove_in("at", r2);
+        }
+        _ => {}
+    }
+    true
+}
+
+fn verb_suffix(w: &mut Word, rv: usize) -> bool {
+    let Some(s) = w.longest_suffix_in(
+        &[
+            "ada", "ida", "ia", "aria", "eria", "iria", "ara", "era", "ira", "ava", "asse", "esse",
+            "isse", "aste", "este", "iste", "ei", "arei", "erei", "irei", "am", "iam", "ariam",
+            "eriam", "iriam", "aram", "eram", "iram", "avam", "em", "arem", "erem", "irem",
+            "assem", "essem", "issem", "ado", "ido", "ando", "endo", "indo", "ara~o", "era~o",
+            "ira~o", "ar", "er", "ir", "as", "adas", "idas", "ias", "arias", "erias", "irias",
+            "aras", "eras", "iras", "avas", "es", "ardes", "erdes", "irdes", "ares", "eres",
+            "ires", "asses", "esses", "isses", "astes", "estes", "istes", "is", "ais", "eis",
+            "areis", "ereis", "ireis", "áreis", "éreis", "íreis", "ásseis", "ésseis", "ísseis",
+            "áveis", "íeis", "aríeis", "eríeis", "iríeis", "ados", "idos", "amos", "áramos",
+            "éramos", "íramos", "ávamos", "íamos", "aríamos", "eríamos", "iríamos", "emos",
+            "aremos", "eremos", "iremos", "ássemos", "êssemos", "íssemos", "imos", "armos",
+            "ermos", "irmos", "ámos", "arás", "erás", "irás", "eu", "iu", "ou", "ará", "erá",
+            "irá",
+        ],
+        rv,
+    ) else {
+        return false;
+    };
+    w.remove(s);
+    true
+}
+
+fn residual_suffix(w: &mut Word, rv: usize) {
+    if let Some(s) = w.longest_suffix_in(&["os", "a", "i", "o", "á", "í", "ó"], rv) {
+        w.remove(s);
+    }
+}
+
+fn residual_form(w: &mut Word, rv: usize) {
+    match w.longest_suffix(&["e", "é", "ê", "ç"]) {
+        Some("ç") => w.replace("ç", "c"),
+        Some(s) if w.start_of(s) >= rv => {
+            w.remove(s);
+            if (w.ends_with("gu") || w.ends_with("ci")) && w.len() > rv {
+                w.chars.pop();
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,108 @@
+//! Snowball Russian stemmer.
+
+use super::Word;
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'а' | 'е' | 'и' | 'о' | 'у' | 'ы' | 'э' | 'ю' | 'я')
+}
+
+const PERFECTIVE_GERUND_AFTER_A: &[&str] = &["в", "вши", "вшись"];
+const PERFECTIVE_GERUND: &[&str] = &["ив", "ивши", "ившись", "ыв", "ывши", "ывшись"];
+
+const ADJECTIVE: &[&str] = &[
+    "ее", "ие", "ые", "ое", "ими", "ыми", "ей", "ий", "ый", "ой", "ем", "им", "ым", "ом", "его",
+    "ого", "ему", "ому", "их", "ых", "ую", "юю", "ая", "яя", "ою", "ею",
+];
+
+const PARTICIPLE_AFTER_A: &[&str] = &["ем", "нн", "вш", "ющ", "щ"];
+const PARTICIPLE: &[&str] = &["ивш", "ывш", "ующ"];
+
+const VERB_AFTER_A: &[&str] = &[
+    "ла", "на", "ете", "йте", "ли", "й", "л", "ем", "н", "ло", "но", "ет", "ют", "ны", "ть", "ешь",
+    "нно",
+];
+const VERB: &[&str] = &[
+    "ила", "ыла", "ена", "ейте", "уйте", "ите", "или", "ыли", "ей", "уй", "ил", "ыл", "им", "ым",
+    "ен", "ило", "ыло", "ено", "ят", "ует", "уют", "ит", "ыт", "ены", "ить", "ыть", "ишь", "ую",
+    "ю",
+];
+
+const NOUN: &[&str] = &[
+    "а", "ев", "ов", "ие", "ье", "е", "иями", "ями", "ами", "еи", "ии", "и", "ией", "ей", "ой",
+    "ий", "й", "иям", "ям", "ием", "ем", "ам", "ом", "о", "у", "ах", "иях", "ях", "ы", "ь", "ию",
+    "ью", "ю", "ия", "ья", "я",
+];
+
+pub(super) fn stem(w: &mut Word) {
+    w.map_chars(|c| if c == 'ё' { 'е' } else { c });
+
+    // Everything happens inside RV, the region after the first vowel.
+    let rv = w
+        .chars
+        .iter()
+        .position(|&c| is_vowel(c))
+        .map_or(w.len(), |i| i + 1);
+    let (_, r2) = w.regions(is_vowel);
+
+    if !remove_either(w, PERFECTIVE_GERUND_AFTER_A, PERFECTIVE_GERUND, rv) {
+        if let Some(s) = w.longest_suffix_in(&["ся", "сь"], rv) {
+            w.remove(s);
+        }
+        if !adjectival(w, rv) && !remove_either(w, VERB_AFTER_A, VERB, rv) {
+            if let Some(s) = w.longest_suffix_in(NOUN, rv) {
+                w.remove(s);
+            }
+        }
+    }
+
+    w.remove_in("и", rv);
+
+    if let Some(s) = w.longest_suffix_in(&["ост", "ость"], rv) {
+        if w.start_of(s) >= r2 {
+            w.remove(s);
+        }
+    }
+
+    // Superlative (then undouble н), a double н, or a final soft sign.
+    match w.longest_suffix_in(&["ейш", "ейше", "н", "ь"], rv) {
+        Some(s @ ("ейш" | "ейше")) => {
+            w.remove(s);
+            undouble_n(w, rv);
+        }
+        Some("н") => undouble_n(w, rv),
+        Some("ь") => w.remove("ь"),
+        _ => {}
+    }
+}
+
+fn undouble_n(w: &mut Word, rv: usize) {
+    if w.ends_with("нн") && w.start_of("нн") >= rv {
+        w.chars.pop();
+    }
+}
+
+/// Remove the longest suffix from either list; suffixes from `after_a` only count when
+/// preceded by а or я. All of it must lie in RV.
+fn remove_either(w: &mut Word, after_a: &[&str], other: &[&str], rv: usize) -> bool {
+    let longest = [after_a, other]
+        .into_iter()
+        .filter_map(|list| w.longest_suffix_in(list, rv))
+        .max_by_key(|s| s.chars().count());
+    let Some(s) = longest else {
+        return false;
+    };
+    if after_a.contains(&s) && !(w.start_of(s) > rv && matches!(w.before(s), Some('а' | 'я'))) {
+        return false;
+    }
+    w.remove(s);
+    true
+}
+
+fn adjectival(w: &mut Word, rv: usize) -> bool {
+    let Some(s) = w.longest_suffix_in(ADJECTIVE, rv) else {
+        return false;
+    };
+    w.remove(s);
+    remove_either(w, PARTICIPLE_AFTER_A, PARTICIPLE, rv);
+    true
+}
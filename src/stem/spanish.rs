@@ -0,0 +1,166 @@
+//! Snowball Spanish stemmer.
+
+use super::Word;
+
+fn is_vowel(c: char) -> bool {
+    matches!(
+        c,
+        'a' | 'e' | 'i' | 'o' | 'u' | 'á' | 'é' | 'í' | 'ó' | 'ú' | 'ü'
+    )
+}
+
+pub(super) fn stem(w: &mut Word) {
+    let rv = w.romance_rv(is_vowel);
+    let (r1, r2) = w.regions(is_vowel);
+
+    attached_pronoun(w, rv);
+    if !standard_suffix(w, r1, r2) && !y_verb_suffix(w, rv) {
+        verb_suffix(w, rv);
+    }
+    residual_suffix(w, rv);
+
+    w.map_chars(|c| match c {
+        'á' => 'a',
+        'é' => 'e',
+        'í' => 'i',
+        'ó' => 'o',
+        'ú' => 'u',
+        c => c,
+    });
+}
+
+fn attached_pronoun(w: &mut Word, rv: usize) {
+    let Some(pronoun) = w.longest_suffix(&[
+        "me", "se", "sela", "selo", "selas", "selos", "la", "le", "lo", "las", "les", "los", "nos",
+    ]) else {
+        return;
+    };
+    let end = w.start_of(pronoun);
+    let Some(verb) = w.longest_suffix_at(
+        end,
+        &[
+            "iéndo", "ándo", "ár", "ér", "ír", "ando", "iendo", "ar", "er", "ir", "yendo",
+        ],
+        rv,
+    ) else {
+        return;
+    };
+    let verb_start = end - verb.chars().count();
+    if verb == "yendo" && !(verb_start > 0 && w.chars[verb_start - 1] == 'u') {
+        return;
+    }
+    w.remove(pronoun);
+    let unaccented = match verb {
+        "iéndo" => "iendo",
+        "ándo" => "ando",
+        "ár" => "ar",
+        "ér" => "er",
+        "ír" => "ir",
+        _ => return,
+    };
+    w.replace(verb, unaccented);
+}
+
+fn standard_suffix(w: &mut Word, r1: usize, r2: usize) -> bool {
+    let Some(s) = w.longest_suffix(&[
+        "anza", "anzas", "ico", "ica", "icos", "icas", "ismo", "ismos", "able", "ables", "ible",
+        "ibles", "ista", "istas", "oso", "osa", "osos", "osas", "amiento", "amientos", "imiento",
+        "imientos", "adora", "ador", "ación", "adoras", "adores", "aciones", "ante", "antes",
+        "ancia", "ancias", "logía", "logías", "ución", "uciones", "encia", "encias", "amente",
+        "mente", "idad", "idades", "iva", "ivo", "ivas", "ivos",
+    ]) else {
+        return false;
+    };
+    let region = if s == "amente" { r1 } else { r2 };
+    if w.start_of(s) < region {
+        return false;
+    }
+    w.remove(s);
+    match s {
+        "adora" | "ador" | "ación" | "adoras" | "adores" | "aciones" | "ante" | "antes"
+        | "ancia" | "ancias" => {
+            w.remove_in("ic", r2);
+        }
+        "logía" | "logías" => w.push_str("log"),
+        "ución" | "uciones" => w.push_str("u"),
+        "encia" | "encias" => w.push_str("ente"),
+        "amente" => match w.longest_suffix(&["iv", "os", "ic", "ad"]) {
+            Some("iv") if w.start_of("iv") >= r2 => {
+                w.remove("iv");
+                w.remove_in("at", r2);
+            }
+            Some(t) => {
+                w.remove_in(t, r2);
+            }
+            None => {}
+        },
+        "mente" => {
+            if let Some(t) = w.longest_suffix(&["ante", "able", "ible"]) {
+                w.remove_in(t, r2);
+            }
+        }
+        "idad" | "idades" => {
+            if let Some(t) = w.longest_suffix(&["abil", "ic", "iv"]) {
+                w.remove_in(t, r2);
+            }
+        }
+        "iva" | "ivo" | "ivas" | "ivos" => {
+            w.remove_in("at", r2);
+        }
+        _ => {}
+    }
+    true
+}
+
+fn y_verb_suffix(w: &mut Word, rv: usize) -> bool {
+    let Some(s) = w.longest_suffix_in(
+        &[
+            "ya", "ye", "yan", "yen", "yeron", "yendo", "yo", "yó", "yas", "yes", "yais", "yamos",
+        ],
+        rv,
+    ) else {
+        return false;
+    };
+    if w.before(s) == Some('u') {
+        w.remove(s);
+        return true;
+    }
+    false
+}
+
+fn verb_suffix(w: &mut Word, rv: usize) {
+    let Some(s) = w.longest_suffix_in(
+        &[
+            "aba", "ada", "ida", "ara", "iera", "ía", "aría", "ería", "iría", "ad", "ed", "id",
+            "ase", "iese", "aste", "iste", "an", "aban", "aran", "ieran", "ían", "arían", "erían",
+            "irían", "en", "asen", "iesen", "aron", "ieron", "arán", "erán", "irán", "ado", "ido",
+            "ando", "iendo", "ar", "er", "ir", "as", "abas", "adas", "idas", "aras", "ieras",
+            "ías", "arías", "erías", "irías", "es", "ases", "ieses", "abais", "arais", "ierais",
+            "íais", "aríais", "eríais", "iríais", "aseis", "ieseis", "asteis", "isteis", "áis",
+            "éis", "aréis", "eréis", "iréis", "ados", "idos", "amos", "ábamos", "áramos",
+            "iéramos", "íamos", "aríamos", "eríamos", "iríamos", "emos", "aremos", "eremos",
+            "iremos", "ásemos", "iésemos", "imos", "arás", "erás", "irás", "ís", "ará", "erá",
+            "irá", "aré", "eré", "iré", "ió",
+        ],
+        rv,
+    ) else {
+        return;
+    };
+    w.remove(s);
+    if matches!(s, "en" | "es" | "éis" | "emos") && w.ends_with("gu") {
+        w.chars.pop();
+    }
+}
+
+fn residual_suffix(w: &mut Word, rv: usize) {
+    match w.longest_suffix(&["os", "a", "o", "á", "í", "ó", "e", "é"]) {
+        Some(s @ ("e" | "é")) if w.start_of(s) >= rv => {
+            w.remove(s);
+            if w.ends_with("gu") && w.start_of("u") >= rv {
+                w.chars.pop();
+            }
+        }
+        Some(s) if w.start_of(s) >= rv => w.remove(s),
+        _ => {}
+    }
+}
@@ -0,0 +1,44 @@
+//! Snowball Swedish stemmer.
+
+use super::Word;
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y' | 'ä' | 'å' | 'ö')
+}
+
+fn is_s_ending(c: char) -> bool {
+    "bcdfghjklmnoprtvy".contains(c)
+}
+
+pub(super) fn stem(w: &mut Word) {
+    // Only R1 is used; it is adjusted so that the region before it contains at least 3
+    // letters.
+    let r1 = w.region_after(0, is_vowel).max(3);
+
+    if let Some(s) = w.longest_suffix_in(
+        &[
+            "a", "arna", "erna", "heterna", "orna", "ad", "e", "ade", "ande", "arne", "are",
+            "aste", "en", "anden", "aren", "heten", "ern", "ar", "er", "heter", "or", "s", "as",
+            "arnas", "ernas", "ornas", "es", "ades", "andes", "ens", "arens", "hetens", "erns",
+            "at", "andet", "het", "ast",
+        ],
+        r1,
+    ) {
+        if s != "s" || w.before(s).is_some_and(is_s_ending) {
+            w.remove(s);
+        }
+    }
+
+    if w.longest_suffix_in(&["dd", "gd", "nn", "dt", "gt", "kt", "tt"], r1)
+        .is_some()
+    {
+        w.chars.pop();
+    }
+
+    match w.longest_suffix_in(&["lig", "ig", "els", "löst", "fullt"], r1) {
+        Some("löst") => w.replace("löst", "lös"),
+        Some("fullt") => w.replace("fullt", "full"),
+        Some(s) => w.remove(s),
+        None => {}
+    }
+}
@@ -1,5 +1,6 @@
 //! Stopword lists.
 
+use crate::lang::Language;
 use std::collections::HashSet;
 
 pub const ENGLISH: &[&str] = &[
@@ -132,9 +133,10 @@ pub const ENGLISH: &[&str] = &[
     "yourselves",
 ];
 
+/// Stopwords for a language code (see [`Language::from_code`]); empty if there is no list.
 pub fn get(lang: &str) -> HashSet<String> {
-    match lang.to_lowercase().as_str() {
-        "en" | "english" => ENGLISH.iter().map(|s| s.to_string()).collect(),
+    match Language::from_code(lang) {
+        Some(Language::English) => ENGLISH.iter().map(|s| s.to_string()).collect(),
         _ => HashSet::new(),
     }
 }
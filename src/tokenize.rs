@@ -1,55 +1,410 @@
 //! Text tokenization utilities.
+//!
+//! The word functions use UAX #29 word boundaries. For other definitions of a token
+//! (whitespace, punctuation kept as tokens, a user regex), use a [`Tokenizer`]; for
+//! Chinese and Japanese, which UAX #29 splits into single characters, use [`cjk`], and
+//! for Thai, Lao, Khmer and Myanmar, [`sea`].
 
+pub mod cjk;
+mod document;
+pub mod identifier;
+mod kind;
+pub mod sea;
+mod sentence;
+mod tokenizer;
+mod web;
+
+pub use document::{Document, Paragraph, ParagraphTokenizer, Sentence};
+pub use kind::TokenKind;
+pub use sentence::SentenceSplitter;
+#[cfg(feature = "regex")]
+pub use tokenizer::RegexTokenizer;
+pub use tokenizer::{
+    GraphemeTokenizer, PunctuationTokenizer, TokenRef, Tokenizer, WhitespaceTokenizer,
+    WordTokenizer,
+};
+pub use web::WebTokenizer;
+
+use crate::offset::{OffsetCursor, OffsetUnit};
+use crate::ScrubConfig;
+use std::sync::OnceLock;
 use unicode_segmentation::UnicodeSegmentation;
 
 pub fn words(text: &str) -> Vec<&str> {
     text.unicode_words().collect()
 }
 
+/// UAX #29 sentences. These split after every `.` followed by an uppercase word
+/// (`"Dr. Smith"`); [`sentences_with_offsets`] applies abbreviation and list rules.
 pub fn sentences(text: &str) -> Vec<&str> {
     text.unicode_sentences().collect()
 }
 
+/// Extended grapheme clusters (UAX #29): what a reader sees as one character.
+///
+/// Emoji ZWJ sequences (`👨‍👩‍👧`), flags (`🇯🇵`) and base + combining mark (`e\u{301}`)
+/// each stay one cluster.
+pub fn graphemes(text: &str) -> Vec<&str> {
+    text.graphemes(true).collect()
+}
+
+/// A word token and its position in the source text.
+///
+/// `start`/`end` are in whatever [`OffsetUnit`] the producing function was asked for
+/// (chars for [`tokenize_with_offsets`]). This is the owned form of a [`TokenRef`];
+/// when the tokens are only looked at, [`tokenize_iter`] avoids the allocations.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Token {
     pub text: String,
     pub start: usize,
     pub end: usize,
+    /// What the token looks like; missing in serialized data means [`TokenKind::Word`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub kind: TokenKind,
 }
 
+/// Tokenize into words with **char** offsets.
+///
+/// Equivalent to `tokenize_with_offsets_in(text, OffsetUnit::Char)`, and to collecting
+/// [`tokenize_iter`] into owned tokens.
 pub fn tokenize_with_offsets(text: &str) -> Vec<Token> {
-    let mut tokens = Vec::new();
-    // `unicode_word_indices()` yields (byte_offset, &str). Converting byte→char offsets
-    // via `text[..byte_idx].chars().count()` is correct but can be \(O(n^2)\) overall.
-    // Instead, advance incrementally from the last word boundary.
-    let mut last_byte = 0usize;
-    let mut last_char = 0usize;
-
-    for (byte_idx, word) in text.unicode_word_indices() {
-        // Advance char counter from last_byte → byte_idx.
-        if byte_idx >= last_byte {
-            last_char += text[last_byte..byte_idx].chars().count();
-        } else {
-            // Defensive: should not happen (monotonic iterator), but keep correctness.
-            last_char = text[..byte_idx].chars().count();
+    tokenize_iter(text).map(|t| t.to_token(text)).collect()
+}
+
+/// The words of `text`, produced lazily as borrowed [`TokenRef`]s with byte and char
+/// offsets. Nothing is allocated.
+///
+/// ```
+/// use textprep::tokenize::tokenize_iter;
+///
+/// let t = tokenize_iter("Grüße, Welt").nth(1).unwrap();
+/// assert_eq!((t.text, t.byte_start, t.char_start), ("Welt", 9, Some(7)));
+/// ```
+pub fn tokenize_iter(text: &str) -> TokenIter<'_> {
+    TokenIter {
+        text,
+        words: text.unicode_word_indices(),
+        byte: 0,
+        chars: 0,
+    }
+}
+
+/// Iterator over the words of a text; see [`tokenize_iter`].
+#[derive(Debug)]
+pub struct TokenIter<'a> {
+    text: &'a str,
+    words: unicode_segmentation::UnicodeWordIndices<'a>,
+    /// Byte offset up to which chars have been counted, and their count.
+    byte: usize,
+    chars: usize,
+}
+
+impl<'a> Iterator for TokenIter<'a> {
+    type Item = TokenRef<'a>;
+
+    fn next(&mut self) -> Option<TokenRef<'a>> {
+        // Same filter as `WordTokenizer`.
+        let (i, w) = self
+            .words
+            .find(|(_, w)| !w.is_empty() && !w.chars().any(char::is_whitespace))?;
+        let mut t = TokenRef::new(w, i);
+        self.chars += self.text[self.byte..i].chars().count();
+        t.char_start = Some(self.chars);
+        self.chars += w.chars().count();
+        t.char_end = Some(self.chars);
+        self.byte = t.byte_end;
+        Some(t)
+    }
+}
+
+/// Tokenize into words, reporting `start`/`end` in the requested [`OffsetUnit`].
+///
+/// With [`OffsetUnit::Byte`], `&text[t.start..t.end] == t.text` and no char counting
+/// is performed.
+pub fn tokenize_with_offsets_in(text: &str, unit: OffsetUnit) -> Vec<Token> {
+    WordTokenizer.tokenize_with_offsets_in(text, unit)
+}
+
+/// Tokenize `text` into words normalized by `cfg`, with **char** offsets into `text`.
+///
+/// Equivalent to `tokenize_scrubbed_in(text, cfg, OffsetUnit::Char)`.
+///
+/// ```
+/// use textprep::tokenize::tokenize_scrubbed;
+/// use textprep::ScrubConfig;
+///
+/// let text = "Ｆｕｌｌ\u{200B}y ﬁled";
+/// let tokens = tokenize_scrubbed(text, &ScrubConfig::search_key_strict_invisibles());
+/// let spans: Vec<_> = tokens.iter().map(|t| (t.text.as_str(), t.start, t.end)).collect();
+/// assert_eq!(spans, [("fully", 0, 6), ("filed", 7, 11)]);
+/// ```
+pub fn tokenize_scrubbed(text: &str, cfg: &ScrubConfig) -> Vec<Token> {
+    tokenize_scrubbed_in(text, cfg, OffsetUnit::Char)
+}
+
+/// Tokenize `text` into words normalized by `cfg`, reporting `start`/`end` of the
+/// original words in the requested [`OffsetUnit`].
+///
+/// Each word of `text` is scrubbed with [`crate::scrub_with`] on its own, so `t.text`
+/// is the normalized form while the span still covers what the user wrote, however
+/// NFKC, case folding or zero-width removal changed the length. Words separated only
+/// by characters `cfg` removes (`Straße\u{200B}n`) are scrubbed as one. When
+/// normalization splits a word, every part gets the span of the whole word. Symbols
+/// that normalize into words (NFKC `㎏` → `kg`, `㍻` → `平成`) become tokens spanning
+/// the symbol. Steps that look across words, like Chinese phrase conversion between
+/// single-character Han words, only see one word at a time.
+pub fn tokenize_scrubbed_in(text: &str, cfg: &ScrubConfig, unit: OffsetUnit) -> Vec<Token> {
+    let mut words = Vec::new();
+    WordTokenizer.tokenize_into(text, &mut words);
+    let mut cursor = OffsetCursor::new(text, unit);
+    let mut out = Vec::with_capacity(words.len());
+    let mut gap_start = 0;
+    let mut i = 0;
+    while i < words.len() {
+        let start = words[i].byte_start;
+        let mut end = words[i].byte_end;
+        while let Some(next) = words
+            .get(i + 1)
+            .filter(|next| scrubs_away(&text[end..next.byte_start], cfg))
+        {
+            end = next.byte_end;
+            i += 1;
         }
+        i += 1;
+        push_scrubbed_gap(text, gap_start, start, cfg, &mut cursor, &mut out);
+        push_scrubbed(text, start, end, cfg, &mut cursor, &mut out);
+        gap_start = end;
+    }
+    push_scrubbed_gap(text, gap_start, text.len(), cfg, &mut cursor, &mut out);
+    out
+}
+
+/// Scrub `text[start..end]` and push its words, each spanning the whole range.
+fn push_scrubbed(
+    text: &str,
+    start: usize,
+    end: usize,
+    cfg: &ScrubConfig,
+    cursor: &mut OffsetCursor<'_>,
+    out: &mut Vec<Token>,
+) {
+    let scrubbed = crate::scrub_with(&text[start..end], cfg);
+    let (s, e) = (cursor.advance_to(start), cursor.advance_to(end));
+    out.extend(tokenize_iter(&scrubbed).map(|p| Token {
+        text: p.text.to_string(),
+        start: s,
+        end: e,
+        kind: p.kind,
+    }));
+}
+
+/// Push the words that symbols between words scrub into (`㎏` → `kg`), one grapheme
+/// cluster at a time. ASCII punctuation and whitespace never become words.
+fn push_scrubbed_gap(
+    text: &str,
+    start: usize,
+    end: usize,
+    cfg: &ScrubConfig,
+    cursor: &mut OffsetCursor<'_>,
+    out: &mut Vec<Token>,
+) {
+    for (i, g) in text[start..end].grapheme_indices(true) {
+        if !g.is_ascii() {
+            push_scrubbed(text, start + i, start + i + g.len(), cfg, cursor, out);
+        }
+    }
+}
+
+/// Whether `gap` is non-empty and made only of characters `cfg` removes.
+fn scrubs_away(gap: &str, cfg: &ScrubConfig) -> bool {
+    let mut buf = [0u8; 4];
+    !gap.is_empty()
+        && gap.chars().all(|c| {
+            let c = &*c.encode_utf8(&mut buf);
+            (cfg.remove_zero_width && crate::unicode::contains_zero_width(c))
+                || (cfg.remove_bidi_controls && crate::unicode::contains_bidi_controls(c))
+        })
+}
 
-        let start = last_char;
-        let len = word.chars().count();
-        // Defensive: `unicode_word_indices()` should not return whitespace-containing tokens,
-        // but some inputs (control chars / edge cases) can produce surprising results.
-        // We only keep tokens that contain at least one non-whitespace char and no whitespace.
-        if !word.is_empty() && word.chars().all(|c| !c.is_whitespace()) {
-            tokens.push(Token {
-                text: word.to_string(),
-                start,
-                end: start + len,
-            });
+/// Sentences with **char** offsets, split by the English [`SentenceSplitter`].
+///
+/// Equivalent to `sentences_with_offsets_in(text, OffsetUnit::Char)`. For another
+/// language, use [`SentenceSplitter::new`] and its [`Tokenizer`] methods.
+pub fn sentences_with_offsets(text: &str) -> Vec<Token> {
+    sentences_with_offsets_in(text, OffsetUnit::Char)
+}
+
+/// Sentences, reporting `start`/`end` in the requested [`OffsetUnit`].
+pub fn sentences_with_offsets_in(text: &str, unit: OffsetUnit) -> Vec<Token> {
+    english_sentences().tokenize_with_offsets_in(text, unit)
+}
+
+fn english_sentences() -> &'static SentenceSplitter {
+    static ENGLISH: OnceLock<SentenceSplitter> = OnceLock::new();
+    ENGLISH.get_or_init(SentenceSplitter::default)
+}
+
+/// Paragraphs (separated by blank lines) with **char** offsets; see
+/// [`ParagraphTokenizer`].
+///
+/// Equivalent to `paragraphs_with_offsets_in(text, OffsetUnit::Char)`.
+pub fn paragraphs_with_offsets(text: &str) -> Vec<Token> {
+    paragraphs_with_offsets_in(text, OffsetUnit::Char)
+}
+
+/// Paragraphs, reporting `start`/`end` in the requested [`OffsetUnit`].
+pub fn paragraphs_with_offsets_in(text: &str, unit: OffsetUnit) -> Vec<Token> {
+    ParagraphTokenizer.tokenize_with_offsets_in(text, unit)
+}
+
+/// Lines with **char** offsets, without their terminators.
+///
+/// Lines end at LF, CR, CRLF, NEL (U+0085), LINE SEPARATOR (U+2028) and PARAGRAPH
+/// SEPARATOR (U+2029). Empty lines are kept (with empty `text`), so the index of a
+/// token is its line number; as with [`str::lines`], a final terminator does not
+/// start another line.
+///
+/// Equivalent to `lines_with_offsets_in(text, OffsetUnit::Char)`.
+pub fn lines_with_offsets(text: &str) -> Vec<Token> {
+    lines_with_offsets_in(text, OffsetUnit::Char)
+}
+
+/// Lines, reporting `start`/`end` in the requested [`OffsetUnit`].
+pub fn lines_with_offsets_in(text: &str, unit: OffsetUnit) -> Vec<Token> {
+    let mut cursor = OffsetCursor::new(text, unit);
+    document::line_spans(text)
+        .map(|(s, e, _)| Token {
+            text: text[s..e].to_string(),
+            start: cursor.advance_to(s),
+            end: cursor.advance_to(e),
+            kind: TokenKind::of(&text[s..e]),
+        })
+        .collect()
+}
+
+/// Grapheme clusters with **char** offsets, one token per cluster (whitespace
+/// included), so that the tokens cover the whole text.
+///
+/// Equivalent to `graphemes_with_offsets_in(text, OffsetUnit::Char)`.
+pub fn graphemes_with_offsets(text: &str) -> Vec<Token> {
+    graphemes_with_offsets_in(text, OffsetUnit::Char)
+}
+
+/// Grapheme clusters, reporting `start`/`end` in the requested [`OffsetUnit`].
+pub fn graphemes_with_offsets_in(text: &str, unit: OffsetUnit) -> Vec<Token> {
+    GraphemeTokenizer.tokenize_with_offsets_in(text, unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize_with_offsets_in_units() {
+        let text = "Müller 😀 Tōkyō";
+        let bytes = tokenize_with_offsets_in(text, OffsetUnit::Byte);
+        for t in &bytes {
+            assert_eq!(&text[t.start..t.end], t.text);
         }
 
-        last_byte = byte_idx + word.len();
-        last_char = start + len;
+        let chars = tokenize_with_offsets_in(text, OffsetUnit::Char);
+        let utf16 = tokenize_with_offsets_in(text, OffsetUnit::Utf16);
+        let last = |ts: &[Token]| (ts.last().unwrap().start, ts.last().unwrap().end);
+        assert_eq!(last(&bytes), (13, 20));
+        assert_eq!(last(&chars), (9, 14));
+        assert_eq!(last(&utf16), (10, 15));
+        assert_eq!(chars, tokenize_with_offsets(text));
+    }
+
+    #[test]
+    fn test_graphemes_with_offsets_keep_clusters_whole() {
+        let text = "👨\u{200D}👩\u{200D}👧 🇯🇵 e\u{301}!";
+        assert_eq!(graphemes(text).len(), 6);
+        let tokens = graphemes_with_offsets(text);
+        let spans: Vec<(usize, usize)> = tokens.iter().map(|t| (t.start, t.end)).collect();
+        assert_eq!(spans, [(0, 5), (5, 6), (6, 8), (8, 9), (9, 11), (11, 12)]);
+        assert_eq!(tokens[4].text, "e\u{301}");
+        let utf16 = graphemes_with_offsets_in(text, OffsetUnit::Utf16);
+        assert_eq!((utf16[0].start, utf16[0].end), (0, 8));
+    }
+
+    #[test]
+    fn test_sentences_with_offsets() {
+        let text = "Dr. Müller kam. Er ging.";
+        assert_eq!(sentences(text).len(), 3);
+        let tokens = sentences_with_offsets(text);
+        let spans: Vec<(&str, usize, usize)> = tokens
+            .iter()
+            .map(|t| (t.text.as_str(), t.start, t.end))
+            .collect();
+        assert_eq!(spans, [("Dr. Müller kam.", 0, 15), ("Er ging.", 16, 24)]);
+        let bytes = sentences_with_offsets_in(text, OffsetUnit::Byte);
+        assert_eq!(&text[bytes[1].start..bytes[1].end], "Er ging.");
+    }
+
+    #[test]
+    fn test_tokenize_scrubbed_spans_point_into_original() {
+        let text = "Straße\u{200B}n ﬁle Ｆｕｌｌ, ㍻";
+        let cfg = ScrubConfig::search_key_strict_invisibles();
+        // Full case folding maps ß to ss; simple lowercasing keeps it.
+        let strassen = if cfg!(feature = "casefold") {
+            "strassen"
+        } else {
+            "straßen"
+        };
+        let tokens = tokenize_scrubbed(text, &cfg);
+        let spans: Vec<(&str, usize, usize)> = tokens
+            .iter()
+            .map(|t| (t.text.as_str(), t.start, t.end))
+            .collect();
+        assert_eq!(
+            spans,
+            [
+                (strassen, 0, 8),
+                ("file", 9, 12),
+                ("full", 13, 17),
+                ("平", 19, 20),
+                ("成", 19, 20),
+            ]
+        );
+
+        // Without zero-width removal the ZWSP stays a word boundary.
+        let plain =
+            tokenize_scrubbed_in("Straße\u{200B}n", &ScrubConfig::default(), OffsetUnit::Byte);
+        let texts: Vec<(&str, usize)> = plain.iter().map(|t| (t.text.as_str(), t.start)).collect();
+        assert_eq!(texts, [("straße", 0), ("n", 10)]);
+    }
+
+    #[test]
+    fn test_lines_and_paragraphs_with_offsets() {
+        let text = "é1\r\né2\rx\u{2028}\n\r\n \nlast";
+        let tokens = lines_with_offsets(text);
+        let lines: Vec<(&str, usize, usize)> = tokens
+            .iter()
+            .map(|t| (t.text.as_str(), t.start, t.end))
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                ("é1", 0, 2),
+                ("é2", 4, 6),
+                ("x", 7, 8),
+                ("", 9, 9),
+                ("", 10, 10),
+                (" ", 12, 13),
+                ("last", 14, 18),
+            ]
+        );
+        let bytes = lines_with_offsets_in(text, OffsetUnit::Byte);
+        for t in &bytes {
+            assert_eq!(&text[t.start..t.end], t.text);
+        }
+        let paras = paragraphs_with_offsets(text);
+        let spans: Vec<(&str, usize, usize)> = paras
+            .iter()
+            .map(|t| (t.text.as_str(), t.start, t.end))
+            .collect();
+        assert_eq!(spans, [("é1\r\né2\rx", 0, 8), ("last", 14, 18)]);
     }
-    tokens
 }
@@ -0,0 +1,250 @@
+//! Dictionary-based word segmentation for Chinese and Japanese.
+//!
+//! UAX #29 has no word boundaries inside Han text, so [`super::words`] yields one token
+//! per character. [`CjkSegmenter`] instead finds the most probable split of each run of
+//! Han and kana characters, given a word frequency dictionary, as jieba does: every
+//! dictionary word occurring in the run is an edge of a DAG over character positions,
+//! and dynamic programming picks the path maximizing the product of word
+//! probabilities (`freq / total`).
+//!
+//! Characters the dictionary does not know are their own single-character words;
+//! consecutive unknown characters of the same script are merged into one unknown word
+//! (`ひらがな`, rare names). Unlike jieba there is no HMM to discover unknown words made
+//! of known characters; add them with [`CjkSegmenter::add_word`].
+//!
+//! The bundled dictionary is a trimmed copy of jieba's: about 10,000 common simplified
+//! Chinese words and 5,000 characters. It is MIT licensed; the copyright and license
+//! notice is at the top of `data/cjk_words.txt`. Load the full jieba `dict.txt`, or
+//! any `word freq` list, with [`CjkSegmenter::from_dict`].
+
+use super::tokenizer::tokenize_runs;
+use super::{Token, TokenRef, Tokenizer};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use unicode_script::{Script, UnicodeScript};
+
+static WORDS: &str = include_str!("data/cjk_words.txt");
+
+/// A word frequency dictionary and the segmenter that uses it.
+///
+/// As a [`Tokenizer`], non-CJK text is split into UAX #29 words (punctuation dropped,
+/// as [`super::WordTokenizer`] does) and each run of Han / kana characters is
+/// segmented with the dictionary.
+#[derive(Debug, Clone, Default)]
+pub struct CjkSegmenter {
+    /// Word frequencies; every proper prefix of a word is present with frequency 0 so
+    /// the DAG scan knows when to stop.
+    freq: HashMap<String, u32>,
+    total: u64,
+    words: usize,
+}
+
+impl CjkSegmenter {
+    /// An empty dictionary: every character is its own word, except that runs of
+    /// unknown characters of the same script are merged.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a segmenter from a dictionary with one `word freq` entry per line.
+    ///
+    /// Fields are separated by whitespace and anything after the frequency (jieba's
+    /// part-of-speech tag) is ignored, so jieba's `dict.txt` loads as is. A missing
+    /// frequency counts as 1. Blank lines and `#` comments are skipped.
+    pub fn from_dict(dict: &str) -> Self {
+        let mut segmenter = Self::new();
+        for line in dict.lines() {
+            let mut fields = line.split_whitespace();
+            let Some(word) = fields.next().filter(|w| !w.starts_with('#')) else {
+                continue;
+            };
+            let freq = fields.next().and_then(|f| f.parse().ok()).unwrap_or(1);
+            segmenter.add_word(word, freq);
+        }
+        segmenter
+    }
+
+    /// Add `word` with frequency `freq`, replacing any earlier frequency. Higher
+    /// frequencies make the word more likely to be kept whole.
+    pub fn add_word(&mut self, word: &str, freq: u32) {
+        if word.is_empty() {
+            return;
+        }
+        let freq = freq.max(1);
+        match self.freq.insert(word.to_string(), freq) {
+            Some(0) | None => self.words += 1,
+            Some(old) => self.total -= u64::from(old),
+        }
+        self.total += u64::from(freq);
+        for (i, _) in word.char_indices().skip(1) {
+            self.freq.entry(word[..i].to_string()).or_insert(0);
+        }
+    }
+
+    /// The frequency of `word`, if it is in the dictionary.
+    pub fn freq(&self, word: &str) -> Option<u32> {
+        self.freq.get(word).copied().filter(|&f| f > 0)
+    }
+
+    /// Number of words in the dictionary.
+    pub fn len(&self) -> usize {
+        self.words
+    }
+
+    /// Whether the dictionary has no words.
+    pub fn is_empty(&self) -> bool {
+        self.words == 0
+    }
+
+    /// The words of `text`: segmented CJK runs and UAX #29 words elsewhere.
+    pub fn segment<'a>(&self, text: &'a str) -> Vec<&'a str> {
+        let mut tokens = Vec::new();
+        self.tokenize_into(text, &mut tokens);
+        tokens.into_iter().map(|t| t.text).collect()
+    }
+
+    /// Segment a run of CJK characters starting at byte `base`, appending to `out`.
+    fn segment_run<'a>(&self, run: &'a str, base: usize, out: &mut Vec<TokenRef<'a>>) {
+        let bounds: Vec<usize> = run
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain([run.len()])
+            .collect();
+        let n = bounds.len() - 1;
+        let ln_total = (self.total.max(1) as f64).ln();
+        // best[i] = (log probability of the best split of run[i..], end of its first word)
+        let mut best = vec![(0.0f64, n); n + 1];
+        for i in (0..n).rev() {
+            let single = self.freq(&run[bounds[i]..bounds[i + 1]]).unwrap_or(1);
+            let mut top = ((single as f64).ln() - ln_total + best[i + 1].0, i + 1);
+            for j in i + 2..=n {
+                match self.freq.get(&run[bounds[i]..bounds[j]]) {
+                    None => break,
+                    Some(0) => {}
+                    Some(&f) => {
+                        let score = (f as f64).ln() - ln_total + best[j].0;
+                        if score > top.0 {
+                            top = (score, j);
+                        }
+                    }
+                }
+            }
+            best[i] = top;
+        }
+
+        // Script of the run of unknown characters the last pushed token holds, if any.
+        let mut unknown: Option<Script> = None;
+        let mut i = 0;
+        while i < n {
+            let j = best[i].1;
+            let (start, end) = (bounds[i], bounds[j]);
+            let word = &run[start..end];
+            let script = (j == i + 1 && self.freq(word).is_none())
+                .then(|| word.chars().next().map(cjk_script))
+                .flatten();
+            match out.last_mut() {
+                // Consecutive unknown characters of the same script form one word.
+                Some(last) if script.is_some() && unknown == script => {
+                    last.byte_end = base + end;
+                    last.text = &run[last.byte_start - base..end];
+                }
+                _ => out.push(TokenRef::new(word, base + start)),
+            }
+            unknown = script;
+            i = j;
+        }
+    }
+}
+
+impl Tokenizer for CjkSegmenter {
+    fn tokenize_into<'a>(&self, text: &'a str, out: &mut Vec<TokenRef<'a>>) {
+        tokenize_runs(text, is_cjk, out, |run, base, out| {
+            self.segment_run(run, base, out)
+        });
+    }
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(
+        c.script(),
+        Script::Han | Script::Hiragana | Script::Katakana
+    ) || matches!(c, 'ー' | '々' | '〆' | '〇')
+}
+
+/// The script an unknown character belongs to, with the Japanese length mark and
+/// iteration marks counted as kana / Han.
+fn cjk_script(c: char) -> Script {
+    match c {
+        'ー' => Script::Katakana,
+        '々' | '〆' | '〇' => Script::Han,
+        _ => c.script(),
+    }
+}
+
+/// The segmenter with the bundled dictionary.
+pub fn bundled() -> &'static CjkSegmenter {
+    static DICT: OnceLock<CjkSegmenter> = OnceLock::new();
+    DICT.get_or_init(|| CjkSegmenter::from_dict(WORDS))
+}
+
+/// Segment `text` with the bundled dictionary.
+pub fn segment(text: &str) -> Vec<&str> {
+    bundled().segment(text)
+}
+
+/// Segment `text` with the bundled dictionary, with **char** offsets.
+pub fn segment_with_offsets(text: &str) -> Vec<Token> {
+    bundled().tokenize_with_offsets(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_segmentation() {
+        assert_eq!(
+            segment("我来到北京清华大学"),
+            ["我", "来到", "北京", "清华大学"]
+        );
+        assert_eq!(
+            segment("他说：iPhone手机很便宜。"),
+            ["他", "说", "iPhone", "手机", "很", "便宜"]
+        );
+    }
+
+    #[test]
+    fn test_user_dictionary_and_unknown_words() {
+        let mut seg = CjkSegmenter::from_dict("# test\n研究 100 n\n研究生 50\n生命 80\n起源 90\n");
+        assert_eq!(seg.len(), 4);
+        assert_eq!(seg.freq("研"), None);
+        assert_eq!(seg.segment("研究生命起源"), ["研究", "生命", "起源"]);
+        // Unknown characters of one script merge; a script change splits.
+        assert_eq!(
+            seg.segment("ひらがなカタカナ研究"),
+            ["ひらがな", "カタカナ", "研究"]
+        );
+        seg.add_word("起源", 1);
+        seg.add_word("生命起源", 500);
+        assert_eq!(seg.segment("研究生命起源"), ["研究", "生命起源"]);
+    }
+
+    #[test]
+    fn test_offsets() {
+        let text = "Rust 是一种编程语言。";
+        let tokens = segment_with_offsets(text);
+        let chars: Vec<char> = text.chars().collect();
+        for t in &tokens {
+            assert_eq!(chars[t.start..t.end].iter().collect::<String>(), t.text);
+        }
+        assert_eq!(tokens[0].text, "Rust");
+        assert_eq!(tokens.last().map(|t| t.end), Some(12));
+    }
+
+    #[test]
+    fn test_long_unknown_run_is_linear() {
+        // The bundled dictionary has no kana: 40,000 unknown characters in one run.
+        let text = "ひらがな".repeat(10_000);
+        assert_eq!(segment(&text), [text.as_str()]);
+    }
+}
@@ -0,0 +1,242 @@
+//! Lines, paragraphs, and the [`Document`] view nesting paragraphs → sentences →
+//! tokens.
+
+use super::{TokenRef, Tokenizer, WordTokenizer};
+
+/// The Unicode line terminators: LF, CR, CRLF, NEL (U+0085), LS (U+2028) and PS
+/// (U+2029).
+///
+/// Yields `(start, end, terminator)` for each line, with `text[start..end]` the line
+/// without its terminator. A terminator at the very end does not start another
+/// (empty) line, as with [`str::lines`].
+pub(super) fn line_spans(text: &str) -> impl Iterator<Item = (usize, usize, &str)> + '_ {
+    let mut pos = 0;
+    std::iter::from_fn(move || {
+        if pos >= text.len() {
+            return None;
+        }
+        let start = pos;
+        let Some(i) = text[start..].find(['\n', '\r', '\u{85}', '\u{2028}', '\u{2029}']) else {
+            pos = text.len();
+            return Some((start, text.len(), ""));
+        };
+        let end = start + i;
+        let rest = &text[end..];
+        let len = if rest.starts_with("\r\n") {
+            2
+        } else {
+            rest.chars().next().map_or(0, char::len_utf8)
+        };
+        pos = end + len;
+        Some((start, end, &text[end..pos]))
+    })
+}
+
+/// Splits text into paragraphs: runs of lines separated by blank lines.
+///
+/// A line holding only whitespace counts as blank, and any line terminator (see
+/// [`super::lines_with_offsets`]) separates lines, so `"a\r\n \r\nb"` is two
+/// paragraphs. A PARAGRAPH SEPARATOR (U+2029) also ends a paragraph on its own.
+/// Paragraphs are returned without surrounding whitespace; line breaks inside a
+/// paragraph are kept.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ParagraphTokenizer;
+
+impl Tokenizer for ParagraphTokenizer {
+    fn tokenize_into<'a>(&self, text: &'a str, out: &mut Vec<TokenRef<'a>>) {
+        out.clear();
+        let mut para: Option<(usize, usize)> = None;
+        let flush = |para: &mut Option<(usize, usize)>, out: &mut Vec<TokenRef<'a>>| {
+            if let Some((s, e)) = para.take() {
+                out.push(TokenRef::new(&text[s..e], s));
+            }
+        };
+        for (start, end, terminator) in line_spans(text) {
+            let line = &text[start..end];
+            let trimmed = line.trim_start();
+            if trimmed.is_empty() {
+                flush(&mut para, out);
+                continue;
+            }
+            let s = start + (line.len() - trimmed.len());
+            let e = start + line.trim_end().len();
+            para = Some((para.map_or(s, |(s, _)| s), e));
+            if terminator == "\u{2029}" {
+                flush(&mut para, out);
+            }
+        }
+        flush(&mut para, out);
+    }
+}
+
+/// A text split into paragraphs, each paragraph into sentences and each sentence into
+/// tokens.
+///
+/// Every [`TokenRef`] in the view (paragraph and sentence spans included) has byte and
+/// char offsets into the whole text, not into its parent.
+///
+/// ```
+/// use textprep::tokenize::Document;
+///
+/// let doc = Document::new("Title\r\n\r\nDr. Smith came. He left.\n");
+/// assert_eq!(doc.paragraphs().len(), 2);
+/// let second = &doc.paragraphs()[1];
+/// let sentences: Vec<&str> = second.sentences.iter().map(|s| s.span.text).collect();
+/// assert_eq!(sentences, ["Dr. Smith came.", "He left."]);
+/// let he = &second.sentences[1].tokens[0];
+/// assert_eq!((he.text, he.char_start), ("He", Some(25)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Document<'a> {
+    text: &'a str,
+    paragraphs: Vec<Paragraph<'a>>,
+}
+
+/// A paragraph of a [`Document`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Paragraph<'a> {
+    /// The paragraph text and its offsets.
+    pub span: TokenRef<'a>,
+    /// The sentences of the paragraph, in order.
+    pub sentences: Vec<Sentence<'a>>,
+}
+
+/// A sentence of a [`Paragraph`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sentence<'a> {
+    /// The sentence text and its offsets.
+    pub span: TokenRef<'a>,
+    /// The tokens of the sentence, in order.
+    pub tokens: Vec<TokenRef<'a>>,
+}
+
+impl<'a> Document<'a> {
+    /// Split `text` with the English [`super::SentenceSplitter`] and [`WordTokenizer`].
+    pub fn new(text: &'a str) -> Self {
+        Self::with_tokenizers(text, super::english_sentences(), &WordTokenizer)
+    }
+
+    /// Split `text` into paragraphs, each paragraph with `sentences` and each sentence
+    /// with `words`.
+    pub fn with_tokenizers(
+        text: &'a str,
+        sentences: &dyn Tokenizer,
+        words: &dyn Tokenizer,
+    ) -> Self {
+        let paragraphs = ParagraphTokenizer
+            .tokenize(text)
+            .into_iter()
+            .map(|para| Paragraph {
+                span: para,
+                sentences: sentences
+                    .tokenize(para.text)
+                    .into_iter()
+                    .map(|s| {
+                        let span = shift(s, &para);
+                        Sentence {
+                            span,
+                            tokens: words
+                                .tokenize(span.text)
+                                .into_iter()
+                                .map(|t| shift(t, &span))
+                                .collect(),
+                        }
+                    })
+                    .collect(),
+            })
+            .collect();
+        Self { text, paragraphs }
+    }
+
+    /// The whole text.
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// All paragraphs, in order.
+    pub fn paragraphs(&self) -> &[Paragraph<'a>] {
+        &self.paragraphs
+    }
+
+    /// All sentences, in order.
+    pub fn sentences(&self) -> impl Iterator<Item = &Sentence<'a>> + '_ {
+        self.paragraphs.iter().flat_map(|p| &p.sentences)
+    }
+
+    /// All tokens, in order.
+    pub fn tokens(&self) -> impl Iterator<Item = &TokenRef<'a>> + '_ {
+        self.sentences().flat_map(|s| &s.tokens)
+    }
+}
+
+/// `t`, found inside `parent.text`, with offsets into the text `parent` came from.
+fn shift<'a>(mut t: TokenRef<'a>, parent: &TokenRef<'a>) -> TokenRef<'a> {
+    t.byte_start += parent.byte_start;
+    t.byte_end += parent.byte_start;
+    let base = |c: Option<usize>| c.zip(parent.char_start).map(|(c, p)| c + p);
+    t.char_start = base(t.char_start);
+    t.char_end = base(t.char_end);
+    t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_spans() {
+        let text = "a\nb\r\nc\rd\u{85}e\u{2028}f\u{2029}\r\n";
+        let lines: Vec<(&str, &str)> = line_spans(text).map(|(s, e, t)| (&text[s..e], t)).collect();
+        assert_eq!(
+            lines,
+            [
+                ("a", "\n"),
+                ("b", "\r\n"),
+                ("c", "\r"),
+                ("d", "\u{85}"),
+                ("e", "\u{2028}"),
+                ("f", "\u{2029}"),
+                ("", "\r\n"),
+            ]
+        );
+        assert_eq!(line_spans("").count(), 0);
+        assert_eq!(line_spans("x").collect::<Vec<_>>(), [(0, 1, "")]);
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        let texts = |t| {
+            ParagraphTokenizer
+                .tokenize(t)
+                .into_iter()
+                .map(|p| p.text)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            texts("  One\r\nline two.\r\n \t\r\n\r\nThree\n"),
+            ["One\r\nline two.", "Three"]
+        );
+        assert_eq!(texts("a\u{2029}b\n\nc"), ["a", "b", "c"]);
+        assert!(texts(" \n\r\n ").is_empty());
+    }
+
+    #[test]
+    fn test_document_offsets_point_into_the_text() {
+        let text = "Grüße!\n\nÉté. Hiver.";
+        let doc = Document::new(text);
+        let chars: Vec<char> = text.chars().collect();
+        for t in doc.tokens() {
+            assert_eq!(&text[t.byte_start..t.byte_end], t.text);
+            assert_eq!(
+                chars[t.char_start.unwrap()..t.char_end.unwrap()]
+                    .iter()
+                    .collect::<String>(),
+                t.text
+            );
+        }
+        let words: Vec<&str> = doc.tokens().map(|t| t.text).collect();
+        assert_eq!(words, ["Grüße", "Été", "Hiver"]);
+        assert_eq!(doc.sentences().count(), 3);
+        assert_eq!(doc.paragraphs()[1].span.char_start, Some(8));
+    }
+}
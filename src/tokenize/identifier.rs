@@ -0,0 +1,224 @@
+//! Splitting source-code identifiers and converting between case styles.
+//!
+//! `parseHTTPResponse2XX`, `snake_case_name` and `kebab-case` are single UAX #29
+//! words. [`split`] breaks them into their parts:
+//!
+//! - at `_`, `-`, `.` and any other character that is not a letter, digit or mark;
+//! - before an uppercase letter that follows a lowercase one (`parse|HTTP`);
+//! - before the last letter of an acronym followed by lowercase (`HTTP|Response`);
+//! - between letters and digits (`Response|2|XX`).
+//!
+//! [`to_case`] joins the parts in another style. There, digits stay attached to the
+//! part they were written against, so `base64Encode` becomes `base64_encode`, not
+//! `base_64_encode`.
+
+use super::{Token, TokenRef, Tokenizer};
+use crate::offset::OffsetUnit;
+use unicode_properties::{GeneralCategoryGroup, UnicodeGeneralCategory};
+
+/// Splits identifiers (or any text) into identifier parts, as [`split`] does.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IdentifierTokenizer;
+
+impl Tokenizer for IdentifierTokenizer {
+    fn tokenize_into<'a>(&self, text: &'a str, out: &mut Vec<TokenRef<'a>>) {
+        out.clear();
+        out.extend(parts(text).map(|(s, e)| TokenRef::new(&text[s..e], s)));
+    }
+}
+
+/// A case style for [`to_case`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Case {
+    /// `parse_http_response`
+    Snake,
+    /// `PARSE_HTTP_RESPONSE`
+    ScreamingSnake,
+    /// `parse-http-response`
+    Kebab,
+    /// `parseHttpResponse`
+    Camel,
+    /// `ParseHttpResponse`
+    Pascal,
+    /// `Parse Http Response`
+    Title,
+}
+
+/// The parts of `ident`: `"parseHTTPResponse2XX"` → `["parse", "HTTP", "Response", "2",
+/// "XX"]`.
+pub fn split(ident: &str) -> Vec<&str> {
+    parts(ident).map(|(s, e)| &ident[s..e]).collect()
+}
+
+/// The parts of `ident` with **char** offsets.
+pub fn split_with_offsets(ident: &str) -> Vec<Token> {
+    IdentifierTokenizer.tokenize_with_offsets(ident)
+}
+
+/// The parts of `ident`, with `start`/`end` in the requested [`OffsetUnit`].
+pub fn split_with_offsets_in(ident: &str, unit: OffsetUnit) -> Vec<Token> {
+    IdentifierTokenizer.tokenize_with_offsets_in(ident, unit)
+}
+
+/// `ident` rewritten in `case`: `to_case("parseHTTPResponse", Case::Snake)` →
+/// `"parse_http_response"`.
+pub fn to_case(ident: &str, case: Case) -> String {
+    let mut out = String::with_capacity(ident.len() + 4);
+    to_case_into(ident, case, &mut out);
+    out
+}
+
+/// Like [`to_case`], writing into `out`.
+///
+/// `out` is cleared first. This is useful when you want to reuse allocations in hot
+/// loops.
+pub fn to_case_into(ident: &str, case: Case, out: &mut String) {
+    out.clear();
+    let separator = match case {
+        Case::Snake | Case::ScreamingSnake => "_",
+        Case::Kebab => "-",
+        Case::Title => " ",
+        Case::Camel | Case::Pascal => "",
+    };
+    for (n, word) in words(ident).enumerate() {
+        if n > 0 {
+            out.push_str(separator);
+        }
+        match case {
+            Case::Snake | Case::Kebab => out.extend(word.chars().flat_map(char::to_lowercase)),
+            Case::ScreamingSnake => out.extend(word.chars().flat_map(char::to_uppercase)),
+            Case::Camel if n == 0 => out.extend(word.chars().flat_map(char::to_lowercase)),
+            Case::Camel | Case::Pascal | Case::Title => {
+                let mut chars = word.chars();
+                if let Some(first) = chars.next() {
+                    out.extend(first.to_uppercase());
+                }
+                out.extend(chars.flat_map(char::to_lowercase));
+            }
+        }
+    }
+}
+
+/// The parts of `ident` for case conversion: like [`parts`], but a run of digits
+/// written right after another part is joined to it.
+fn words(ident: &str) -> impl Iterator<Item = &str> {
+    let mut merged: Vec<(usize, usize)> = Vec::new();
+    for (s, e) in parts(ident) {
+        let digits = ident[s..e].starts_with(|c: char| c.is_numeric());
+        match merged.last_mut() {
+            Some(last) if digits && last.1 == s => last.1 = e,
+            _ => merged.push((s, e)),
+        }
+    }
+    merged.into_iter().map(move |(s, e)| &ident[s..e])
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Class {
+    Upper,
+    Lower,
+    Digit,
+    /// Letters without case (CJK, Arabic, …).
+    Caseless,
+}
+
+fn class(c: char) -> Option<Class> {
+    if c.is_uppercase() {
+        Some(Class::Upper)
+    } else if c.is_lowercase() {
+        Some(Class::Lower)
+    } else if c.is_numeric() {
+        Some(Class::Digit)
+    } else if c.is_alphabetic() {
+        Some(Class::Caseless)
+    } else {
+        None
+    }
+}
+
+/// Byte ranges of the parts of `text`.
+fn parts(text: &str) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let mut out = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut prev: Option<Class> = None;
+    let mut iter = text.char_indices().peekable();
+    while let Some((i, c)) = iter.next() {
+        let Some(k) = class(c) else {
+            // A mark belongs to the letter before it.
+            if c.general_category_group() == GeneralCategoryGroup::Mark && start.is_some() {
+                continue;
+            }
+            if let Some(s) = start.take() {
+                out.push((s, i));
+            }
+            prev = None;
+            continue;
+        };
+        let next = iter.peek().and_then(|&(_, n)| class(n));
+        let boundary = match (prev, k) {
+            (None, _) => false,
+            (Some(Class::Lower | Class::Caseless), Class::Upper) => true,
+            (Some(Class::Upper), Class::Upper) => next == Some(Class::Lower),
+            (Some(p), k) => (p == Class::Digit) != (k == Class::Digit),
+        };
+        if boundary {
+            if let Some(s) = start.replace(i) {
+                out.push((s, i));
+            }
+        } else if start.is_none() {
+            start = Some(i);
+        }
+        prev = Some(k);
+    }
+    if let Some(s) = start {
+        out.push((s, text.len()));
+    }
+    out.into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split() {
+        assert_eq!(
+            split("parseHTTPResponse2XX"),
+            ["parse", "HTTP", "Response", "2", "XX"]
+        );
+        assert_eq!(split("snake_case_name"), ["snake", "case", "name"]);
+        assert_eq!(
+            split("kebab-case.dot.case"),
+            ["kebab", "case", "dot", "case"]
+        );
+        assert_eq!(split("__init__"), ["init"]);
+        assert_eq!(split("XMLHttpRequest"), ["XML", "Http", "Request"]);
+        assert_eq!(split("IOError"), ["IO", "Error"]);
+        assert_eq!(split("naïveÉtéCafé"), ["naïve", "Été", "Café"]);
+        assert_eq!(split("utf8ToUTF16"), ["utf", "8", "To", "UTF", "16"]);
+    }
+
+    #[test]
+    fn test_offsets() {
+        let tokens = split_with_offsets("ünicode_Über");
+        let spans: Vec<_> = tokens.iter().map(|t| (t.start, t.end)).collect();
+        assert_eq!(spans, [(0, 7), (8, 12)]);
+        let bytes = split_with_offsets_in("ünicode_Über", OffsetUnit::Byte);
+        assert_eq!((bytes[1].start, bytes[1].end), (9, 14));
+    }
+
+    #[test]
+    fn test_to_case() {
+        let id = "parseHTTPResponse2XX";
+        assert_eq!(to_case(id, Case::Snake), "parse_http_response2_xx");
+        assert_eq!(to_case(id, Case::ScreamingSnake), "PARSE_HTTP_RESPONSE2_XX");
+        assert_eq!(to_case(id, Case::Kebab), "parse-http-response2-xx");
+        assert_eq!(to_case(id, Case::Camel), "parseHttpResponse2Xx");
+        assert_eq!(to_case(id, Case::Pascal), "ParseHttpResponse2Xx");
+        assert_eq!(to_case(id, Case::Title), "Parse Http Response2 Xx");
+        assert_eq!(to_case("base64_encode", Case::Camel), "base64Encode");
+        assert_eq!(to_case("base64Encode", Case::Snake), "base64_encode");
+        assert_eq!(to_case("", Case::Pascal), "");
+    }
+}
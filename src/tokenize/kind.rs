@@ -0,0 +1,223 @@
+//! Token classification.
+
+use unicode_properties::emoji::{EmojiStatus, UnicodeEmoji};
+use unicode_properties::{GeneralCategory, GeneralCategoryGroup, UnicodeGeneralCategory};
+use unicode_script::{Script, UnicodeScript};
+
+/// What a token looks like, as decided by [`TokenKind::of`].
+///
+/// Every built-in [`super::Tokenizer`] fills this in, so downstream code can skip
+/// numbers, URLs or punctuation without re-parsing the text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TokenKind {
+    /// Letters (and marks / digits mixed in): `"Müller"`, `"e-mail"`, `"C3PO"`.
+    #[default]
+    Word,
+    /// `"42"`, `"-3.14"`, `"1,000"`, `"$5.00"`, `"50%"`.
+    Number,
+    /// Only punctuation: `","`, `"..."`, `"«"`.
+    Punctuation,
+    /// Symbols, possibly mixed with punctuation: `"+"`, `"€"`, `"©"`, `"->"`.
+    Symbol,
+    /// Emoji, including ZWJ sequences, flags and keycaps: `"👍🏽"`, `"🇯🇵"`.
+    Emoji,
+    /// `"https://example.com/a?b"`, `"www.example.com"`.
+    Url,
+    /// `"ada@example.com"`.
+    Email,
+    /// `"#rustlang"`.
+    Hashtag,
+    /// `"@rustlang"`.
+    Mention,
+    /// Han characters, which carry meaning one by one: `"東京"`.
+    Ideograph,
+    /// Only whitespace (only [`super::GraphemeTokenizer`] produces these).
+    Whitespace,
+    /// Anything else, e.g. control characters or `"#1"`.
+    Other,
+}
+
+impl TokenKind {
+    /// Classify `text`, taken as a single token.
+    ///
+    /// The web-shaped kinds (URL, email, hashtag, mention) never contain whitespace, so
+    /// a sentence that starts with `www.` is still [`TokenKind::Word`].
+    pub fn of(text: &str) -> TokenKind {
+        if text.is_empty() {
+            return TokenKind::Other;
+        }
+        if text.chars().all(char::is_whitespace) {
+            return TokenKind::Whitespace;
+        }
+        if !text.chars().any(char::is_whitespace) {
+            if is_url(text) {
+                return TokenKind::Url;
+            }
+            if is_email(text) {
+                return TokenKind::Email;
+            }
+            if let Some(rest) = text.strip_prefix('#') {
+                if is_handle(rest) {
+                    return TokenKind::Hashtag;
+                }
+            }
+            if let Some(rest) = text.strip_prefix('@') {
+                if is_handle(rest) {
+                    return TokenKind::Mention;
+                }
+            }
+        }
+        if is_emoji(text) {
+            return TokenKind::Emoji;
+        }
+        if is_number(text) {
+            return TokenKind::Number;
+        }
+        if text.chars().all(|c| c.script() == Script::Han) {
+            return TokenKind::Ideograph;
+        }
+        if text.chars().any(char::is_alphabetic) {
+            return TokenKind::Word;
+        }
+        let mut has_symbol = false;
+        for c in text.chars() {
+            match c.general_category_group() {
+                GeneralCategoryGroup::Punctuation | GeneralCategoryGroup::Mark => {}
+                GeneralCategoryGroup::Symbol => has_symbol = true,
+                _ => return TokenKind::Other,
+            }
+        }
+        if has_symbol {
+            TokenKind::Symbol
+        } else {
+            TokenKind::Punctuation
+        }
+    }
+
+    /// Whether tokens of this kind are words of running text: [`TokenKind::Word`] or
+    /// [`TokenKind::Ideograph`]. Stemmers and lemmatizers leave everything else alone.
+    pub fn is_word(self) -> bool {
+        matches!(self, TokenKind::Word | TokenKind::Ideograph)
+    }
+}
+
+fn is_url(text: &str) -> bool {
+    ["http://", "https://", "ftp://", "www."]
+        .into_iter()
+        .any(|p| {
+            text.len() > p.len()
+                && text.as_bytes()[..p.len()].eq_ignore_ascii_case(p.as_bytes())
+                && text[p.len()..].starts_with(char::is_alphanumeric)
+        })
+}
+
+fn is_email(text: &str) -> bool {
+    let Some((local, domain)) = text.split_once('@') else {
+        return false;
+    };
+    let local_ok = !local.is_empty()
+        && local
+            .chars()
+            .all(|c| c.is_alphanumeric() || "._%+-".contains(c));
+    let labels_ok = domain
+        .split('.')
+        .all(|label| !label.is_empty() && label.chars().all(|c| c.is_alphanumeric() || c == '-'));
+    local_ok && domain.contains('.') && labels_ok
+}
+
+/// The part of a hashtag or mention after the sigil: word characters, at least one
+/// of them a letter.
+fn is_handle(rest: &str) -> bool {
+    rest.chars().all(|c| c.is_alphanumeric() || c == '_') && rest.chars().any(char::is_alphabetic)
+}
+
+/// Emoji characters and the components that join them, with at least one character
+/// that renders as an emoji by default or an explicit emoji presentation. This keeps
+/// `"©"` and `"#"` (which are `Emoji=YES` but text by default) out.
+fn is_emoji(text: &str) -> bool {
+    let mut presented = false;
+    for c in text.chars() {
+        match c {
+            '\u{FE0F}' | '\u{20E3}' => presented = true,
+            '\u{200D}' | '\u{FE0E}' => {}
+            _ if c.is_emoji_char_or_emoji_component() => {
+                presented |= matches!(
+                    c.emoji_status(),
+                    EmojiStatus::EmojiPresentation
+                        | EmojiStatus::EmojiPresentationAndModifierBase
+                        | EmojiStatus::EmojiPresentationAndEmojiComponent
+                        | EmojiStatus::EmojiPresentationAndModifierAndEmojiComponent
+                );
+            }
+            _ => return false,
+        }
+    }
+    presented
+}
+
+/// Digits with single `.`/`,`/`'`/`_` separators between them, optionally signed,
+/// prefixed with a currency symbol or suffixed with `%`.
+fn is_number(text: &str) -> bool {
+    let text = text.strip_prefix(['+', '-', '−']).unwrap_or(text);
+    let text = match text.chars().next() {
+        Some(c) if c.general_category() == GeneralCategory::CurrencySymbol => &text[c.len_utf8()..],
+        _ => text,
+    };
+    let text = text.strip_suffix('%').unwrap_or(text);
+    let mut prev_digit = false;
+    let mut any = false;
+    for c in text.chars() {
+        if c.is_numeric() {
+            prev_digit = true;
+            any = true;
+        } else if matches!(c, '.' | ',' | '\'' | '_') && prev_digit {
+            prev_digit = false;
+        } else {
+            return false;
+        }
+    }
+    any && prev_digit
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kinds() {
+        let cases = [
+            ("Müller", TokenKind::Word),
+            ("C3PO", TokenKind::Word),
+            ("42", TokenKind::Number),
+            ("-3.14", TokenKind::Number),
+            ("1,000,000", TokenKind::Number),
+            ("$5.00", TokenKind::Number),
+            ("50%", TokenKind::Number),
+            ("...", TokenKind::Punctuation),
+            ("+", TokenKind::Symbol),
+            ("©", TokenKind::Symbol),
+            ("->", TokenKind::Symbol),
+            ("😀", TokenKind::Emoji),
+            ("👍🏽", TokenKind::Emoji),
+            ("👨\u{200D}👩\u{200D}👧", TokenKind::Emoji),
+            ("🇯🇵", TokenKind::Emoji),
+            ("1\u{FE0F}\u{20E3}", TokenKind::Emoji),
+            ("❤\u{FE0F}", TokenKind::Emoji),
+            ("https://example.com/a?b=1", TokenKind::Url),
+            ("www.rust-lang.org", TokenKind::Url),
+            ("ada@example.com", TokenKind::Email),
+            ("#rustlang", TokenKind::Hashtag),
+            ("@rust_lang", TokenKind::Mention),
+            ("#1", TokenKind::Other),
+            ("12:30", TokenKind::Other),
+            ("東京", TokenKind::Ideograph),
+            (" \t", TokenKind::Whitespace),
+            ("\u{1}", TokenKind::Other),
+            ("www.example.com is down.", TokenKind::Word),
+        ];
+        for (text, kind) in cases {
+            assert_eq!(TokenKind::of(text), kind, "{text:?}");
+        }
+    }
+}
@@ -0,0 +1,248 @@
+//! Dictionary-based word segmentation for Thai, Lao, Khmer and Myanmar.
+//!
+//! These scripts are written without spaces between words, and UAX #29 has no rule for
+//! them. [`SeaSegmenter`] splits each run of such text by maximal matching: text is
+//! first cut into clusters that can never contain a word boundary (a consonant with
+//! its marks, a Thai/Lao leading vowel with the consonant after it, a following
+//! `ะ`/`า`/`ำ`, a Khmer coeng or Myanmar virama stack; for Thai these approximate
+//! Thai Character Clusters), then the split into dictionary words covering the most
+//! text with the fewest words is chosen. Clusters no dictionary word covers become
+//! unknown words, merged when adjacent.
+//!
+//! The bundled word list was compiled for this crate and is small: about 400 common
+//! Thai words and a few dozen each for Lao, Khmer and Myanmar. For real use, load a
+//! full list (one word per line, as in PyThaiNLP's or ICU's dictionaries) with
+//! [`SeaSegmenter::from_words`].
+
+use super::tokenizer::tokenize_runs;
+use super::{Token, TokenRef, Tokenizer};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use unicode_properties::{GeneralCategoryGroup, UnicodeGeneralCategory};
+use unicode_script::{Script, UnicodeScript};
+
+static WORDS: &str = include_str!("data/sea_words.txt");
+
+/// A word list and the maximal matching segmenter that uses it.
+///
+/// As a [`Tokenizer`], text in other scripts is split into UAX #29 words (punctuation
+/// dropped, as [`super::WordTokenizer`] does), so offsets line up with
+/// [`super::tokenize_with_offsets`].
+#[derive(Debug, Clone, Default)]
+pub struct SeaSegmenter {
+    /// `true` for words; every proper prefix of a word is present with `false` so the
+    /// scan knows when to stop.
+    words: HashMap<String, bool>,
+    len: usize,
+}
+
+impl SeaSegmenter {
+    /// An empty dictionary: every run of text becomes one unknown word.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a segmenter from a word list, one word per line. Surrounding whitespace,
+    /// blank lines and `#` comments are ignored.
+    pub fn from_words(list: &str) -> Self {
+        let mut segmenter = Self::new();
+        for line in list.lines().map(str::trim) {
+            if !line.is_empty() && !line.starts_with('#') {
+                segmenter.add_word(line);
+            }
+        }
+        segmenter
+    }
+
+    /// Add `word` to the dictionary.
+    pub fn add_word(&mut self, word: &str) {
+        if word.is_empty() {
+            return;
+        }
+        if self.words.insert(word.to_string(), true) != Some(true) {
+            self.len += 1;
+        }
+        for (i, _) in word.char_indices().skip(1) {
+            self.words.entry(word[..i].to_string()).or_insert(false);
+        }
+    }
+
+    /// Whether `word` is in the dictionary.
+    pub fn contains(&self, word: &str) -> bool {
+        self.words.get(word) == Some(&true)
+    }
+
+    /// Number of words in the dictionary.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the dictionary has no words.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The words of `text`: segmented Thai / Lao / Khmer / Myanmar runs and UAX #29
+    /// words elsewhere.
+    pub fn segment<'a>(&self, text: &'a str) -> Vec<&'a str> {
+        let mut tokens = Vec::new();
+        self.tokenize_into(text, &mut tokens);
+        tokens.into_iter().map(|t| t.text).collect()
+    }
+
+    /// Segment a run of Southeast Asian text starting at byte `base`, appending to `out`.
+    fn segment_run<'a>(&self, run: &'a str, base: usize, out: &mut Vec<TokenRef<'a>>) {
+        let bounds = cluster_bounds(run);
+        let m = bounds.len() - 1;
+        // best[i] = ((unknown bytes, words) of the best split of run[i..], end of its
+        // first word, whether that word is known)
+        let mut best = vec![((0usize, 0usize), m, true); m + 1];
+        for i in (0..m).rev() {
+            let cluster = &run[bounds[i]..bounds[i + 1]];
+            let known = self.contains(cluster);
+            let (rest, _, _) = best[i + 1];
+            let unknown = if known { 0 } else { cluster.len() };
+            let mut top = ((rest.0 + unknown, rest.1 + 1), i + 1, known);
+            for j in i + 2..=m {
+                match self.words.get(&run[bounds[i]..bounds[j]]) {
+                    None => break,
+                    Some(false) => {}
+                    Some(true) => {
+                        let (rest, _, _) = best[j];
+                        let cost = (rest.0, rest.1 + 1);
+                        // On a tie the longer first word wins.
+                        if cost <= top.0 {
+                            top = (cost, j, true);
+                        }
+                    }
+                }
+            }
+            best[i] = top;
+        }
+
+        let mut i = 0;
+        let mut unknown_start: Option<usize> = None;
+        while i < m {
+            let (_, j, known) = best[i];
+            if known {
+                if let Some(s) = unknown_start.take() {
+                    out.push(TokenRef::new(&run[s..bounds[i]], base + s));
+                }
+                out.push(TokenRef::new(&run[bounds[i]..bounds[j]], base + bounds[i]));
+            } else if unknown_start.is_none() {
+                unknown_start = Some(bounds[i]);
+            }
+            i = j;
+        }
+        if let Some(s) = unknown_start {
+            out.push(TokenRef::new(&run[s..], base + s));
+        }
+    }
+}
+
+impl Tokenizer for SeaSegmenter {
+    fn tokenize_into<'a>(&self, text: &'a str, out: &mut Vec<TokenRef<'a>>) {
+        tokenize_runs(text, is_sea, out, |run, base, out| {
+            self.segment_run(run, base, out)
+        });
+    }
+}
+
+fn is_sea(c: char) -> bool {
+    matches!(
+        c.script(),
+        Script::Thai | Script::Lao | Script::Khmer | Script::Myanmar
+    )
+}
+
+/// Byte offsets of the cluster boundaries of `run`, including `0` and `run.len()`.
+fn cluster_bounds(run: &str) -> Vec<usize> {
+    let mut bounds = Vec::new();
+    let mut prev: Option<char> = None;
+    for (i, c) in run.char_indices() {
+        let joins_prev = prev
+            .is_some_and(|p| is_leading_vowel(p) || matches!(p, '\u{17D2}' | '\u{1039}'))
+            || matches!(c.general_category_group(), GeneralCategoryGroup::Mark)
+            || matches!(c, '\u{200C}' | '\u{200D}')
+            || is_following_vowel(c);
+        if !joins_prev || prev.is_none() {
+            bounds.push(i);
+        }
+        prev = Some(c);
+    }
+    bounds.push(run.len());
+    bounds
+}
+
+/// Thai and Lao vowels written before the consonant they follow in speech.
+fn is_leading_vowel(c: char) -> bool {
+    matches!(c, '\u{0E40}'..='\u{0E44}' | '\u{0EC0}'..='\u{0EC4}')
+}
+
+/// Thai and Lao vowels (and the repetition marks) that are letters, not marks, but
+/// never start a word.
+fn is_following_vowel(c: char) -> bool {
+    matches!(c, 'ะ' | 'า' | 'ำ' | 'ๆ' | 'ະ' | 'າ' | 'ຳ' | 'ໆ')
+}
+
+/// The segmenter with the bundled word lists.
+pub fn bundled() -> &'static SeaSegmenter {
+    static DICT: OnceLock<SeaSegmenter> = OnceLock::new();
+    DICT.get_or_init(|| SeaSegmenter::from_words(WORDS))
+}
+
+/// Segment `text` with the bundled word lists.
+pub fn segment(text: &str) -> Vec<&str> {
+    bundled().segment(text)
+}
+
+/// Segment `text` with the bundled word lists, with **char** offsets.
+pub fn segment_with_offsets(text: &str) -> Vec<Token> {
+    bundled().tokenize_with_offsets(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_segmentation() {
+        assert_eq!(segment("ผมชอบกินข้าว"), ["ผม", "ชอบ", "กิน", "ข้าว"]);
+        assert_eq!(
+            segment("สวัสดีครับ ภาษาไทยง่ายนิดเดียว"),
+            ["สวัสดี", "ครับ", "ภาษาไทย", "ง่าย", "นิด", "เดียว"]
+        );
+        assert_eq!(segment("ខ្ញុំទៅផ្ទះ"), ["ខ្ញុំ", "ទៅ", "ផ្ទះ"]);
+        assert_eq!(segment("ຂ້ອຍຮຽນພາສາລາວ"), ["ຂ້ອຍ", "ຮຽນ", "ພາສາ", "ລາວ"]);
+        assert_eq!(segment("ကျွန်တော်စာအုပ်ဖတ်"), ["ကျွန်တော်", "စာအုပ်", "ဖတ်"]);
+    }
+
+    #[test]
+    fn test_clusters_and_unknown_words() {
+        let run = "เกียรติ";
+        let clusters: Vec<&str> = cluster_bounds(run)
+            .windows(2)
+            .map(|w| &run[w[0]..w[1]])
+            .collect();
+        assert_eq!(clusters, ["เกี", "ย", "ร", "ติ"]);
+
+        let seg = SeaSegmenter::from_words("# test\nตา\nกลม\nตาก\nลม\n");
+        assert_eq!(seg.len(), 4);
+        assert_eq!(seg.segment("ตากลมกลม"), ["ตาก", "ลม", "กลม"]);
+        assert_eq!(seg.segment("ฝนตกตา"), ["ฝนตก", "ตา"]);
+    }
+
+    #[test]
+    fn test_offsets_match_word_tokenizer() {
+        let text = "Hello, ผมชอบ café!";
+        let tokens = segment_with_offsets(text);
+        let texts: Vec<&str> = tokens.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(texts, ["Hello", "ผม", "ชอบ", "café"]);
+        let words = super::super::tokenize_with_offsets(text);
+        assert_eq!(
+            (tokens[0].start, tokens[0].end),
+            (words[0].start, words[0].end)
+        );
+        assert_eq!((tokens[3].start, tokens[3].end), (13, 17));
+    }
+}
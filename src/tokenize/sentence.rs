@@ -0,0 +1,533 @@
+//! Rule-based sentence splitting on top of UAX #29.
+
+use super::{TokenRef, Tokenizer};
+use crate::lang::Language;
+use std::collections::{HashMap, HashSet};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Splits text into sentences.
+///
+/// Candidate boundaries come from UAX #29 (which handles closing quotes and brackets,
+/// CJK full stops and other scripts' terminators); a boundary is then dropped when:
+///
+/// - the period ends a known abbreviation (`Dr.`, `e.g.`, `z.B.`) or an initialism
+///   (`J.`, `U.S.`), and the next word is not a common sentence starter (`The`, `He`,
+///   `Then`), so `"He got a B. Then he cried."` is still two sentences;
+/// - the "sentence" so far is only a list marker (`1.`, `iv.`, `(a)`);
+/// - a `?`, `!` or ellipsis is followed by a lowercase word (`"Stop!" he said.`);
+/// - it is a single line break inside a sentence (wrapped text), unless the next line
+///   starts a list item (`2. …`, `- …`, `• …`).
+///
+/// A blank line always ends a sentence. Sentences are returned without surrounding
+/// whitespace.
+///
+/// Abbreviation lists are bundled for every [`Language`]; add your own with
+/// [`SentenceSplitter::add_abbreviation`], or learn them from unlabeled text with
+/// [`SentenceSplitter::train`].
+#[derive(Debug, Clone)]
+pub struct SentenceSplitter {
+    /// Lowercase, without the final period (`"dr"`, `"e.g"`).
+    abbreviations: HashSet<String>,
+    /// Capitalized words that start a sentence even after an abbreviation.
+    sentence_starters: HashSet<&'static str>,
+}
+
+impl SentenceSplitter {
+    /// A splitter with the bundled abbreviations for `lang`.
+    pub fn new(lang: Language) -> Self {
+        let mut splitter = Self::empty();
+        for abbr in bundled_abbreviations(lang) {
+            splitter.add_abbreviation(abbr);
+        }
+        splitter
+            .sentence_starters
+            .extend(bundled_sentence_starters(lang));
+        splitter
+    }
+
+    /// Splitter for a language code (see [`Language::from_code`]).
+    pub fn for_code(code: &str) -> Option<Self> {
+        Language::from_code(code).map(Self::new)
+    }
+
+    /// A splitter without abbreviations or sentence starters (initialisms and the other
+    /// rules still apply).
+    pub fn empty() -> Self {
+        Self {
+            abbreviations: HashSet::new(),
+            sentence_starters: HashSet::new(),
+        }
+    }
+
+    /// Add an abbreviation, with or without its final period (`"Dr."`, `"approx"`).
+    /// Matching is case-insensitive.
+    pub fn add_abbreviation(&mut self, abbr: &str) {
+        let abbr = abbr.trim().trim_end_matches('.').to_lowercase();
+        if !abbr.is_empty() {
+            self.abbreviations.insert(abbr);
+        }
+    }
+
+    /// Builder-style variant of [`SentenceSplitter::add_abbreviation`].
+    pub fn with_abbreviations<'s>(mut self, abbrs: impl IntoIterator<Item = &'s str>) -> Self {
+        for abbr in abbrs {
+            self.add_abbreviation(abbr);
+        }
+        self
+    }
+
+    /// Whether `word` (with or without its final period) is a known abbreviation.
+    pub fn is_abbreviation(&self, word: &str) -> bool {
+        self.abbreviations
+            .contains(&word.trim_end_matches('.').to_lowercase())
+    }
+
+    /// Learn abbreviations from unlabeled text, as in Punkt (Kiss & Strunk, 2006).
+    ///
+    /// A word type is taken as an abbreviation when it is (almost) always followed by a
+    /// period, scored with a log-likelihood ratio that favors short types, types with
+    /// internal periods, and types never seen without a period. Works best on a few
+    /// thousand sentences or more of the target domain. Returns the newly learned
+    /// abbreviations, sorted.
+    pub fn train(&mut self, corpus: &str) -> Vec<String> {
+        let mut with_period: HashMap<String, u32> = HashMap::new();
+        let mut without_period: HashMap<String, u32> = HashMap::new();
+        let mut n_tokens = 0u32;
+        let mut n_period = 0u32;
+        for raw in corpus.split_whitespace() {
+            n_tokens += 1;
+            let token = raw
+                .trim_start_matches(is_opening)
+                .trim_end_matches(|c: char| is_closing(c) || matches!(c, ',' | ';' | ':'));
+            let token = token.to_lowercase();
+            if token.ends_with('.') && !token.ends_with("..") {
+                n_period += 1;
+                let typ = token.trim_end_matches('.');
+                if typ.chars().any(char::is_alphabetic) {
+                    *with_period.entry(typ.to_string()).or_default() += 1;
+                }
+            } else if token.chars().any(char::is_alphabetic) {
+                *without_period.entry(token).or_default() += 1;
+            }
+        }
+        if n_tokens == 0 || n_period == 0 || n_period == n_tokens {
+            return Vec::new();
+        }
+
+        let p_period = f64::from(n_period) / f64::from(n_tokens);
+        let mut learned = Vec::new();
+        for (typ, &with) in &with_period {
+            if self.abbreviations.contains(typ) {
+                continue;
+            }
+            let without = without_period.get(typ).copied().unwrap_or(0);
+            let n_periods = typ.matches('.').count() + 1;
+            let n_nonperiods = typ.chars().count() + 1 - n_periods;
+
+            let ll = dunning_log_likelihood(with + without, with, p_period);
+            let f_length = (-(n_nonperiods as f64)).exp();
+            let f_periods = n_periods as f64;
+            let f_penalty = (n_nonperiods as f64).powi(-(without as i32));
+            if ll * f_length * f_periods * f_penalty >= 0.3 {
+                learned.push(typ.clone());
+            }
+        }
+        learned.sort();
+        self.abbreviations.extend(learned.iter().cloned());
+        learned
+    }
+
+    /// Whether the UAX #29 boundary between `text[start..end]` (trimmed) and the
+    /// sentence starting at `next` is a real one.
+    fn is_boundary(&self, text: &str, start: usize, end: usize, next: usize) -> bool {
+        let before = &text[start..end];
+        let gap = &text[end..next];
+        let next_text = &text[next..];
+        let next_char = next_text.chars().next().unwrap_or(' ');
+
+        let line_breaks = gap.matches(['\n', '\u{85}', '\u{2028}']).count()
+            + gap.matches('\r').count()
+            - gap.matches("\r\n").count();
+        if line_breaks >= 2 || gap.contains('\u{2029}') {
+            return true;
+        }
+
+        let core = before.trim_end_matches(is_closing);
+        let Some(last) = core.chars().last() else {
+            return true;
+        };
+        match last {
+            '.' if core.ends_with("..") => !next_char.is_lowercase(),
+            '.' => {
+                if next_char.is_lowercase() || is_list_marker(before) {
+                    return false;
+                }
+                let word = core[..core.len() - 1]
+                    .rsplit(char::is_whitespace)
+                    .next()
+                    .unwrap_or("")
+                    .trim_start_matches(is_opening);
+                if self.is_abbreviation(word) || is_initialism(word) {
+                    // Punkt's orthographic evidence, without a corpus: a capitalized
+                    // word that is rarely anything but a sentence start.
+                    let next_word = next_text
+                        .trim_start_matches(is_opening)
+                        .split(|c: char| !c.is_alphabetic())
+                        .next()
+                        .unwrap_or("");
+                    return self.sentence_starters.contains(next_word);
+                }
+                true
+            }
+            '?' | '!' | '…' | '‼' | '⁇' | '⁈' | '⁉' => !next_char.is_lowercase(),
+            c if is_terminator(c) => true,
+            // No terminator: UAX #29 broke at a line break.
+            _ => starts_list_item(next_text),
+        }
+    }
+}
+
+impl Default for SentenceSplitter {
+    /// The English splitter.
+    fn default() -> Self {
+        Self::new(Language::English)
+    }
+}
+
+impl Tokenizer for SentenceSplitter {
+    fn tokenize_into<'a>(&self, text: &'a str, out: &mut Vec<TokenRef<'a>>) {
+        out.clear();
+        let mut start: Option<usize> = None;
+        let mut end = 0;
+        for (i, segment) in text.split_sentence_bound_indices() {
+            let trimmed = segment.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+            let seg_start = i + (segment.len() - segment.trim_start().len());
+            match start {
+                Some(s) if self.is_boundary(text, s, end, seg_start) => {
+                    out.push(TokenRef::new(&text[s..end], s));
+                    start = Some(seg_start);
+                }
+                Some(_) => {}
+                None => start = Some(seg_start),
+            }
+            end = seg_start + trimmed.len();
+        }
+        if let Some(s) = start {
+            out.push(TokenRef::new(&text[s..end], s));
+        }
+    }
+}
+
+/// Punkt's collocation score for "type is followed by a period": how much better a
+/// period probability of 0.99 explains `count_ab` periods in `count_a` occurrences
+/// than the corpus-wide rate `p1`.
+fn dunning_log_likelihood(count_a: u32, count_ab: u32, p1: f64) -> f64 {
+    let p2 = 0.99f64;
+    let (a, ab) = (f64::from(count_a), f64::from(count_ab));
+    let null_hypo = ab * p1.ln() + (a - ab) * (1.0 - p1).ln();
+    let alt_hypo = ab * p2.ln() + (a - ab) * (1.0 - p2).ln();
+    -2.0 * (null_hypo - alt_hypo)
+}
+
+fn is_closing(c: char) -> bool {
+    matches!(
+        c,
+        '"' | '\'' | ')' | ']' | '}' | '»' | '”' | '’' | '›' | '」' | '』' | '）'
+    )
+}
+
+fn is_opening(c: char) -> bool {
+    matches!(
+        c,
+        '"' | '\'' | '(' | '[' | '{' | '«' | '“' | '‘' | '‹' | '「' | '『' | '（'
+    )
+}
+
+/// Sentence terminators other than `.`, `?`, `!` and the ellipsis.
+fn is_terminator(c: char) -> bool {
+    matches!(
+        c,
+        '。' | '．' | '！' | '？' | '｡' | '؟' | '۔' | '।' | '॥' | '።' | '፧' | '჻' | '︒'
+    )
+}
+
+/// `J`, `U.S`, `e.g`: single letters separated by periods.
+fn is_initialism(word: &str) -> bool {
+    !word.is_empty()
+        && word.split('.').all(|part| {
+            let mut chars = part.chars();
+            matches!((chars.next(), chars.next()), (Some(c), None) if c.is_alphabetic())
+        })
+}
+
+/// `1.`, `12)`, `a.`, `iv.`, `(b)`, `-`, `•`.
+fn is_list_marker(s: &str) -> bool {
+    let s = s.trim();
+    // The longest marker is `(viii)`; this also keeps the check O(1) when called
+    // with a whole sentence.
+    if s.len() > 6 {
+        return false;
+    }
+    if matches!(s, "-" | "*" | "•" | "–" | "‣" | "◦") {
+        return true;
+    }
+    let body = match s.strip_prefix('(') {
+        Some(inner) => inner.strip_suffix(')'),
+        None => s.strip_suffix('.').or_else(|| s.strip_suffix(')')),
+    };
+    let Some(body) = body else {
+        return false;
+    };
+    let is_number = !body.is_empty() && body.len() <= 3 && body.bytes().all(|b| b.is_ascii_digit());
+    let is_letter = body.len() == 1 && body.bytes().all(|b| b.is_ascii_alphabetic());
+    let is_roman = [
+        "i", "ii", "iii", "iv", "v", "vi", "vii", "viii", "ix", "x", "xi", "xii",
+    ]
+    .iter()
+    .any(|r| r.eq_ignore_ascii_case(body));
+    is_number || is_letter || is_roman
+}
+
+/// Whether `text` starts with a list marker followed by whitespace.
+fn starts_list_item(text: &str) -> bool {
+    let mut parts = text.splitn(2, char::is_whitespace);
+    let marker = parts.next().unwrap_or("");
+    parts.next().is_some() && is_list_marker(marker)
+}
+
+/// Abbreviations that are rarely sentence-final, lowercase, without the final period.
+fn bundled_abbreviations(lang: Language) -> &'static [&'static str] {
+    match lang {
+        Language::English => &[
+            "mr", "mrs", "ms", "dr", "prof", "sr", "jr", "st", "mt", "ft", "rev", "hon", "gen",
+            "col", "lt", "capt", "sgt", "cmdr", "adm", "gov", "sen", "rep", "pres", "e.g", "i.e",
+            "vs", "cf", "approx", "nos", "fig", "figs", "vol", "vols", "pp", "dept", "ca", "jan",
+            "feb", "apr", "jun", "jul", "aug", "sep", "sept", "oct", "nov",
+        ],
+        Language::French => &[
+            "m", "mm", "mme", "mmes", "mlle", "mlles", "dr", "pr", "me", "st", "ste", "p.ex", "cf",
+            "av", "bd", "env", "n°", "no", "p", "pp", "vol", "chap", "janv", "févr", "avr", "juil",
+            "sept", "oct", "nov", "déc",
+        ],
+        Language::German => &[
+            "dr", "prof", "hr", "fr", "nr", "bzw", "z.b", "d.h", "u.a", "ca", "evtl", "ggf",
+            "inkl", "vgl", "s", "str", "abs", "bd", "jh", "jhd", "geb", "gest", "dipl", "ing",
+            "st", "z.t", "o.ä", "sog", "u.u", "jan", "feb", "aug", "sept", "okt", "nov", "dez",
+        ],
+        Language::Spanish => &[
+            "sr", "sra", "srta", "sres", "dr", "dra", "ud", "uds", "d", "dña", "p.ej", "pág",
+            "págs", "núm", "n.º", "art", "cap", "vol", "av", "avda", "c", "aprox", "ej", "ene",
+            "feb", "abr", "ago", "sept", "oct", "nov", "dic",
+        ],
+        Language::Italian => &[
+            "sig", "sigg", "sig.ra", "dott", "dott.ssa", "prof", "ing", "avv", "arch", "geom",
+            "rag", "pag", "pagg", "es", "ca", "cap", "art", "vol", "n", "gen", "feb", "apr", "giu",
+            "lug", "ago", "sett", "ott", "nov", "dic",
+        ],
+        Language::Portuguese => &[
+            "sr", "sra", "srta", "dr", "dra", "prof", "profa", "eng", "p.ex", "pág", "págs", "n.º",
+            "art", "cap", "vol", "av", "aprox", "jan", "fev", "abr", "mai", "jun", "jul", "ago",
+            "set", "out", "nov", "dez",
+        ],
+        Language::Dutch => &[
+            "dhr", "mevr", "mw", "dr", "prof", "ir", "drs", "mr", "ing", "bijv", "d.w.z", "o.a",
+            "nr", "blz", "ca", "jl", "resp", "t.a.v", "m.b.t", "z.g.a.n", "jan", "feb", "apr",
+            "aug", "sep", "okt", "nov", "dec",
+        ],
+        Language::Russian => &[
+            "г", "гг", "т.е", "т.к", "т.н", "ул", "д", "стр", "рис", "им", "см", "напр", "проф",
+            "акад", "ср", "тыс", "млн", "млрд", "руб", "коп", "янв", "февр", "апр", "авг", "сент",
+            "окт", "нояб", "дек",
+        ],
+        Language::Swedish => &[
+            "t.ex", "bl.a", "d.v.s", "dvs", "s.k", "nr", "dr", "prof", "ca", "jfr", "f.d", "resp",
+            "o.s.v", "m.m", "fr.o.m", "t.o.m", "kl", "s", "jan", "febr", "aug", "sept", "okt",
+            "nov", "dec",
+        ],
+    }
+}
+
+/// Capitalized words that almost only occur at the start of a sentence: pronouns,
+/// articles and sentence adverbs. Single letters are left out, as they are also
+/// initials (`J. A. Smith`).
+fn bundled_sentence_starters(lang: Language) -> &'static [&'static str] {
+    match lang {
+        Language::English => &[
+            "The", "This", "That", "These", "Those", "There", "Then", "He", "She", "It", "We",
+            "They", "You", "His", "Her", "Its", "Our", "Their", "My", "But", "So", "However",
+            "When", "What", "Why", "How", "Who", "If", "After", "Before", "In", "On", "At", "Now",
+            "Yet", "Still", "Thus", "Later", "Today",
+        ],
+        Language::French => &[
+            "Le", "La", "Les", "Il", "Elle", "Ils", "Elles", "Nous", "Vous", "On", "Je", "Ce",
+            "Cette", "Ces", "Mais", "Puis", "Alors", "Donc", "Quand", "Si", "En", "Dans",
+        ],
+        Language::German => &[
+            "Der", "Die", "Das", "Er", "Sie", "Es", "Wir", "Ihr", "Ich", "Dann", "Aber", "Doch",
+            "Danach", "Heute", "Wenn", "Als", "Dieser", "Diese", "Dieses",
+        ],
+        Language::Spanish => &[
+            "El", "La", "Los", "Las", "Él", "Ella", "Ellos", "Ellas", "Nosotros", "Pero", "Luego",
+            "Entonces", "Después", "Cuando", "Si", "En", "Este", "Esta", "Esto",
+        ],
+        Language::Italian => &[
+            "Il", "Lo", "La", "Gli", "Le", "Lui", "Lei", "Loro", "Noi", "Ma", "Poi", "Allora",
+            "Quando", "Se", "In", "Questo", "Questa",
+        ],
+        Language::Portuguese => &[
+            "Os", "As", "Ele", "Ela", "Eles", "Elas", "Nós", "Mas", "Depois", "Então", "Quando",
+            "Se", "Em", "Este", "Esta", "Isso",
+        ],
+        Language::Dutch => &[
+            "De", "Het", "Hij", "Zij", "Ze", "Wij", "We", "Maar", "Dan", "Daarna", "Toen", "Als",
+            "In", "Dit", "Deze",
+        ],
+        Language::Russian => &[
+            "Он",
+            "Она",
+            "Оно",
+            "Они",
+            "Мы",
+            "Вы",
+            "Это",
+            "Но",
+            "Потом",
+            "Затем",
+            "Когда",
+            "Если",
+            "На",
+        ],
+        Language::Swedish => &[
+            "Han", "Hon", "Den", "Det", "De", "Vi", "Ni", "Men", "Sedan", "Då", "När", "Om",
+            "Denna", "Detta",
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(splitter: &SentenceSplitter, text: &str) -> Vec<String> {
+        splitter
+            .tokenize(text)
+            .into_iter()
+            .map(|t| t.text.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_english_rules() {
+        let en = SentenceSplitter::default();
+        assert_eq!(
+            split(
+                &en,
+                "Dr. Smith arrived at 3.15 p.m. on Friday. He left, e.g. at noon. Bye!"
+            ),
+            [
+                "Dr. Smith arrived at 3.15 p.m. on Friday.",
+                "He left, e.g. at noon.",
+                "Bye!"
+            ]
+        );
+        assert_eq!(
+            split(&en, "J. R. R. Tolkien lived in the U.S. for a while? No."),
+            ["J. R. R. Tolkien lived in the U.S. for a while?", "No."]
+        );
+        assert_eq!(
+            split(&en, "\"Stop!\" he said. She said \"Go.\" Then she left..."),
+            ["\"Stop!\" he said.", "She said \"Go.\"", "Then she left..."]
+        );
+        assert_eq!(
+            split(&en, "Wait... what? I know... Fine."),
+            ["Wait... what?", "I know...", "Fine."]
+        );
+    }
+
+    #[test]
+    fn test_sentence_final_abbreviations_and_initials() {
+        let en = SentenceSplitter::default();
+        for (text, first) in [
+            ("The answer is no. We left.", "The answer is no."),
+            ("I love art. It is nice.", "I love art."),
+            ("He got a B. Then he cried.", "He got a B."),
+            ("Call me at 5 p.m. Then we go.", "Call me at 5 p.m."),
+            ("I was born in Dec. He was not.", "I was born in Dec."),
+            (
+                "We live on Main St. The house is red.",
+                "We live on Main St.",
+            ),
+        ] {
+            assert_eq!(split(&en, text).len(), 2, "{text:?}");
+            assert_eq!(split(&en, text)[0], first);
+        }
+        assert_eq!(
+            split(&en, "Gen. Grant met St. Louis police and J. Smith."),
+            ["Gen. Grant met St. Louis police and J. Smith."]
+        );
+    }
+
+    #[test]
+    fn test_lines_and_lists() {
+        let en = SentenceSplitter::default();
+        assert_eq!(
+            split(
+                &en,
+                "A line\r\nwrapped here.\n\nSteps:\n1. Mix. 2. Bake.\n- serve\n- eat\n\nEnd"
+            ),
+            [
+                "A line\r\nwrapped here.",
+                "Steps:",
+                "1. Mix.",
+                "2. Bake.",
+                "- serve",
+                "- eat",
+                "End"
+            ]
+        );
+        assert_eq!(
+            split(&en, "这是中文。这是第二句！好"),
+            ["这是中文。", "这是第二句！", "好"]
+        );
+        assert!(split(&en, " \n ").is_empty());
+    }
+
+    #[test]
+    fn test_languages_and_user_abbreviations() {
+        let de = SentenceSplitter::for_code("de").unwrap();
+        assert_eq!(
+            split(&de, "Das ist z.B. Herr Nr. 5. Ende."),
+            ["Das ist z.B. Herr Nr. 5.", "Ende."]
+        );
+        let mut en = SentenceSplitter::default();
+        assert_eq!(split(&en, "See Eq. Three.").len(), 2);
+        en.add_abbreviation("Eq.");
+        assert!(en.is_abbreviation("eq"));
+        assert_eq!(split(&en, "See Eq. Three."), ["See Eq. Three."]);
+    }
+
+    #[test]
+    fn test_train_learns_abbreviations() {
+        let nouns = [
+            "lab", "sample", "water", "report", "team", "grams", "result",
+        ];
+        let mut corpus = String::new();
+        for i in 0..100 {
+            let (a, b) = (nouns[i % nouns.len()], nouns[(i * 3 + 1) % nouns.len()]);
+            corpus.push_str(&format!(
+                "The {a} weighed approx. {i} grams near the {b}. Every {b} went to the {a}. "
+            ));
+        }
+        let mut splitter = SentenceSplitter::empty();
+        let learned = splitter.train(&corpus);
+        assert!(learned.contains(&"approx".to_string()), "{learned:?}");
+        assert_eq!(learned, ["approx"]);
+        assert_eq!(
+            split(&splitter, "It weighed approx. Five grams. Done."),
+            ["It weighed approx. Five grams.", "Done."]
+        );
+    }
+}
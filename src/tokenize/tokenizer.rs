@@ -0,0 +1,431 @@
+//! The [`Tokenizer`] trait and the built-in tokenizers.
+
+use super::{Token, TokenKind};
+use crate::offset::{OffsetCursor, OffsetUnit};
+use unicode_properties::{GeneralCategoryGroup, UnicodeGeneralCategory};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A token borrowed from the input text, with byte and char offsets.
+///
+/// `&text[t.byte_start..t.byte_end] == t.text` always holds. `char_start` / `char_end`
+/// are the same span counted in chars; they are filled in by [`Tokenizer::tokenize`]
+/// and [`super::tokenize_iter`], but `None` from [`Tokenizer::tokenize_into`], which
+/// only does the work needed for byte offsets. Convert to an owned [`Token`] (with
+/// char offsets) with [`TokenRef::to_token`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenRef<'a> {
+    pub text: &'a str,
+    pub byte_start: usize,
+    pub byte_end: usize,
+    pub char_start: Option<usize>,
+    pub char_end: Option<usize>,
+    pub kind: TokenKind,
+}
+
+impl<'a> TokenRef<'a> {
+    /// The token at `byte_start` in `text`, classified with [`TokenKind::of`].
+    ///
+    /// Char offsets are left `None`; [`TokenRef::fill_char_offsets`] sets them once all
+    /// tokens are known.
+    pub fn new(text: &'a str, byte_start: usize) -> Self {
+        Self::with_kind(text, byte_start, TokenKind::of(text))
+    }
+
+    /// The token at `byte_start` in `text`, of a kind the tokenizer already knows.
+    pub fn with_kind(text: &'a str, byte_start: usize, kind: TokenKind) -> Self {
+        Self {
+            text,
+            byte_start,
+            byte_end: byte_start + text.len(),
+            char_start: None,
+            char_end: None,
+            kind,
+        }
+    }
+
+    /// Set the char offsets of `tokens` from their byte offsets into `text`.
+    ///
+    /// For tokens in text order, each char of `text` is counted at most once.
+    pub fn fill_char_offsets(text: &str, tokens: &mut [TokenRef<'_>]) {
+        let mut cursor = OffsetCursor::new(text, OffsetUnit::Char);
+        for t in tokens {
+            t.char_start = Some(cursor.advance_to(t.byte_start));
+            t.char_end = Some(cursor.advance_to(t.byte_end));
+        }
+    }
+
+    /// This token as an owned [`Token`] with **char** offsets, `text` being the text it
+    /// was found in. Char offsets already set are used as is; otherwise they are
+    /// counted from `text`.
+    pub fn to_token(&self, text: &str) -> Token {
+        let (start, end) = match (self.char_start, self.char_end) {
+            (Some(start), Some(end)) => (start, end),
+            _ => {
+                let start = text[..self.byte_start].chars().count();
+                (start, start + self.text.chars().count())
+            }
+        };
+        Token {
+            text: self.text.to_string(),
+            start,
+            end,
+            kind: self.kind,
+        }
+    }
+}
+
+/// Splits text into tokens.
+///
+/// Implementations write non-empty, non-overlapping tokens in text order. Code that
+/// works on "words" (similarity, n-grams, keyword matching) takes a `&dyn Tokenizer`
+/// so the definition of a word is the caller's choice.
+pub trait Tokenizer: Send + Sync {
+    /// Write the tokens of `text` into `out`, with byte offsets only (char offsets
+    /// are 0).
+    ///
+    /// `out` is cleared first. This is useful when you want to reuse allocations in hot
+    /// loops.
+    fn tokenize_into<'a>(&self, text: &'a str, out: &mut Vec<TokenRef<'a>>);
+
+    /// The tokens of `text`, with byte and char offsets.
+    fn tokenize<'a>(&self, text: &'a str) -> Vec<TokenRef<'a>> {
+        let mut out = Vec::new();
+        self.tokenize_into(text, &mut out);
+        TokenRef::fill_char_offsets(text, &mut out);
+        out
+    }
+
+    /// The tokens of `text` as owned [`Token`]s with **char** offsets.
+    fn tokenize_with_offsets(&self, text: &str) -> Vec<Token> {
+        self.tokenize_with_offsets_in(text, OffsetUnit::Char)
+    }
+
+    /// The tokens of `text` as owned [`Token`]s, with `start`/`end` in `unit`.
+    ///
+    /// With [`OffsetUnit::Byte`] no char counting is performed.
+    fn tokenize_with_offsets_in(&self, text: &str, unit: OffsetUnit) -> Vec<Token> {
+        let mut tokens = Vec::new();
+        self.tokenize_into(text, &mut tokens);
+        let mut cursor = OffsetCursor::new(text, unit);
+        tokens
+            .into_iter()
+            .map(|t| Token {
+                text: t.text.to_string(),
+                start: cursor.advance_to(t.byte_start),
+                end: cursor.advance_to(t.byte_end),
+                kind: t.kind,
+            })
+            .collect()
+    }
+}
+
+/// Splits on Unicode whitespace, like [`str::split_whitespace`]. Punctuation stays
+/// attached: `"C++, e-mail"` → `["C++,", "e-mail"]`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WhitespaceTokenizer;
+
+impl Tokenizer for WhitespaceTokenizer {
+    fn tokenize_into<'a>(&self, text: &'a str, out: &mut Vec<TokenRef<'a>>) {
+        out.clear();
+        let mut start = None;
+        for (i, c) in text.char_indices() {
+            match (c.is_whitespace(), start) {
+                (true, Some(s)) => {
+                    out.push(TokenRef::new(&text[s..i], s));
+                    start = None;
+                }
+                (false, None) => start = Some(i),
+                _ => {}
+            }
+        }
+        if let Some(s) = start {
+            out.push(TokenRef::new(&text[s..], s));
+        }
+    }
+}
+
+/// UAX #29 words, as returned by [`super::words`] and [`super::tokenize_with_offsets`].
+/// Punctuation is dropped: `"C++, e-mail"` → `["C", "e", "mail"]`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WordTokenizer;
+
+impl Tokenizer for WordTokenizer {
+    fn tokenize_into<'a>(&self, text: &'a str, out: &mut Vec<TokenRef<'a>>) {
+        out.clear();
+        // Defensive: `unicode_word_indices()` should not return whitespace-containing
+        // tokens, but some inputs (control chars / edge cases) can produce surprising
+        // results. Only tokens without whitespace are kept.
+        out.extend(
+            text.unicode_word_indices()
+                .filter(|(_, w)| !w.is_empty() && !w.chars().any(char::is_whitespace))
+                .map(|(i, w)| TokenRef::new(w, i)),
+        );
+    }
+}
+
+/// UAX #29 words plus punctuation and symbols as tokens of their own.
+///
+/// Every non-whitespace segment between UAX #29 word boundaries is kept, and runs of
+/// the same punctuation character are merged into one token:
+/// `"C++, e-mail..."` → `["C", "++", ",", "e", "-", "mail", "..."]`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PunctuationTokenizer;
+
+impl Tokenizer for PunctuationTokenizer {
+    fn tokenize_into<'a>(&self, text: &'a str, out: &mut Vec<TokenRef<'a>>) {
+        out.clear();
+        push_word_bound_segments(text, 0, out);
+    }
+}
+
+/// Append the [`PunctuationTokenizer`] tokens of `text`, which starts at byte `base` of
+/// the caller's text.
+pub(super) fn push_word_bound_segments<'a>(
+    text: &'a str,
+    base: usize,
+    out: &mut Vec<TokenRef<'a>>,
+) {
+    let first = out.len();
+    // The punctuation char the last token is a run of, so merging stays O(1).
+    let mut run_char = None;
+    for (i, segment) in text.split_word_bound_indices() {
+        if segment.chars().all(char::is_whitespace) {
+            continue;
+        }
+        let i = base + i;
+        let repeated = punctuation_run_char(segment);
+        if let Some(last) = out[first..].last_mut() {
+            if last.byte_end == i
+                && repeated.is_some_and(|c| c.len_utf8() == segment.len())
+                && repeated == run_char
+            {
+                last.byte_end += segment.len();
+                last.text = &text[last.byte_start - base..last.byte_end - base];
+                continue;
+            }
+        }
+        run_char = repeated;
+        out.push(TokenRef::new(segment, i));
+    }
+}
+
+/// Tokenize `text` into UAX #29 words, except that runs of adjacent words made only of
+/// `in_run` characters are handed to `segment_run(run, byte_start, out)` instead.
+///
+/// This is how the dictionary segmenters ([`super::cjk`], [`super::sea`]) fit into
+/// the word tokenization of the text around them.
+pub(super) fn tokenize_runs<'a>(
+    text: &'a str,
+    in_run: fn(char) -> bool,
+    out: &mut Vec<TokenRef<'a>>,
+    mut segment_run: impl FnMut(&'a str, usize, &mut Vec<TokenRef<'a>>),
+) {
+    out.clear();
+    // Byte range of the current run.
+    let mut run: Option<(usize, usize)> = None;
+    for (i, w) in text.unicode_word_indices() {
+        if w.chars().any(char::is_whitespace) {
+            continue;
+        }
+        if w.chars().all(in_run) {
+            match &mut run {
+                Some((_, e)) if *e == i => *e = i + w.len(),
+                _ => {
+                    if let Some((s, e)) = run.replace((i, i + w.len())) {
+                        segment_run(&text[s..e], s, out);
+                    }
+                }
+            }
+            continue;
+        }
+        if let Some((s, e)) = run.take() {
+            segment_run(&text[s..e], s, out);
+        }
+        out.push(TokenRef::new(w, i));
+    }
+    if let Some((s, e)) = run {
+        segment_run(&text[s..e], s, out);
+    }
+}
+
+/// The punctuation char `s` consists of, if it is a run of one.
+fn punctuation_run_char(s: &str) -> Option<char> {
+    let c = s.chars().next()?;
+    let is_punct =
+        c.is_ascii_punctuation() || c.general_category_group() == GeneralCategoryGroup::Punctuation;
+    (is_punct && s.chars().all(|r| r == c)).then_some(c)
+}
+
+/// Extended grapheme clusters (UAX #29), one token each, whitespace included.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GraphemeTokenizer;
+
+impl Tokenizer for GraphemeTokenizer {
+    fn tokenize_into<'a>(&self, text: &'a str, out: &mut Vec<TokenRef<'a>>) {
+        out.clear();
+        out.extend(
+            text.grapheme_indices(true)
+                .map(|(i, g)| TokenRef::new(g, i)),
+        );
+    }
+}
+
+/// Tokens defined by a regular expression.
+///
+/// By default each match is a token (`\w+|[^\w\s]+` gives NLTK-style word/punctuation
+/// splitting); with [`RegexTokenizer::gaps`], matches separate tokens instead. Empty
+/// matches and empty gaps are skipped. Requires the `regex` feature.
+#[cfg(feature = "regex")]
+#[derive(Debug, Clone)]
+pub struct RegexTokenizer {
+    re: regex::Regex,
+    gaps: bool,
+}
+
+#[cfg(feature = "regex")]
+impl RegexTokenizer {
+    /// Tokens are the matches of `pattern`.
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        regex::Regex::new(pattern).map(Self::from_regex)
+    }
+
+    /// Tokens are the text between matches of `pattern`.
+    pub fn gaps(pattern: &str) -> Result<Self, regex::Error> {
+        let mut tokenizer = Self::new(pattern)?;
+        tokenizer.gaps = true;
+        Ok(tokenizer)
+    }
+
+    /// Tokens are the matches of an already compiled regex.
+    pub fn from_regex(re: regex::Regex) -> Self {
+        Self { re, gaps: false }
+    }
+}
+
+#[cfg(feature = "regex")]
+impl Tokenizer for RegexTokenizer {
+    fn tokenize_into<'a>(&self, text: &'a str, out: &mut Vec<TokenRef<'a>>) {
+        out.clear();
+        if !self.gaps {
+            out.extend(
+                self.re
+                    .find_iter(text)
+                    .filter(|m| !m.is_empty())
+                    .map(|m| TokenRef::new(m.as_str(), m.start())),
+            );
+            return;
+        }
+        let mut start = 0;
+        for m in self.re.find_iter(text) {
+            if m.start() > start {
+                out.push(TokenRef::new(&text[start..m.start()], start));
+            }
+            start = start.max(m.end());
+        }
+        if start < text.len() {
+            out.push(TokenRef::new(&text[start..], start));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(tokenizer: &dyn Tokenizer, text: &str) -> Vec<String> {
+        tokenizer
+            .tokenize(text)
+            .into_iter()
+            .map(|t| t.text.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_builtin_tokenizers() {
+        let text = "C++, e-mail... Tōkyō!";
+        assert_eq!(
+            texts(&WhitespaceTokenizer, text),
+            ["C++,", "e-mail...", "Tōkyō!"]
+        );
+        assert_eq!(texts(&WordTokenizer, text), ["C", "e", "mail", "Tōkyō"]);
+        assert_eq!(
+            texts(&PunctuationTokenizer, text),
+            ["C", "++", ",", "e", "-", "mail", "...", "Tōkyō", "!"]
+        );
+        assert_eq!(texts(&PunctuationTokenizer, "?!"), ["?", "!"]);
+    }
+
+    #[test]
+    fn test_spans_slice_the_input() {
+        let text = " \u{3000}Müller 😀😀 a--b\t";
+        for tokenizer in [
+            &WhitespaceTokenizer as &dyn Tokenizer,
+            &WordTokenizer,
+            &PunctuationTokenizer,
+        ] {
+            for t in tokenizer.tokenize(text) {
+                assert_eq!(&text[t.byte_start..t.byte_end], t.text);
+            }
+        }
+        assert_eq!(
+            WordTokenizer.tokenize_with_offsets(text),
+            super::super::tokenize_with_offsets(text)
+        );
+        let chars = WhitespaceTokenizer.tokenize_with_offsets(text);
+        assert_eq!((chars[1].start, chars[1].end), (9, 11));
+    }
+
+    #[test]
+    fn test_char_offsets_are_filled_by_tokenize() {
+        let text = "héllo wörld";
+        let mut raw = Vec::new();
+        WordTokenizer.tokenize_into(text, &mut raw);
+        assert_eq!((raw[1].char_start, raw[1].char_end), (None, None));
+        let tokens = WordTokenizer.tokenize(text);
+        assert_eq!(
+            (tokens[1].char_start, tokens[1].char_end),
+            (Some(6), Some(11))
+        );
+        assert_eq!(raw[1].to_token(text), tokens[1].to_token(text));
+        assert_eq!(
+            (raw[1].to_token(text).start, raw[1].to_token(text).end),
+            (6, 11)
+        );
+    }
+
+    #[test]
+    fn test_tokens_carry_kinds() {
+        let tokens = WhitespaceTokenizer.tokenize_with_offsets("see https://x.org for 3.5% 🙂");
+        let kinds: Vec<_> = tokens.iter().map(|t| t.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                TokenKind::Word,
+                TokenKind::Url,
+                TokenKind::Word,
+                TokenKind::Number,
+                TokenKind::Emoji
+            ]
+        );
+        let words: Vec<_> = PunctuationTokenizer
+            .tokenize("Pay $5, now!")
+            .into_iter()
+            .filter(|t| t.kind.is_word())
+            .map(|t| t.text)
+            .collect();
+        assert_eq!(words, ["Pay", "now"]);
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn test_regex_tokenizer() {
+        let wordpunct = RegexTokenizer::new(r"\w+|[^\w\s]+").unwrap();
+        assert_eq!(
+            texts(&wordpunct, "C++, e-mail"),
+            ["C", "++,", "e", "-", "mail"]
+        );
+        let gaps = RegexTokenizer::gaps(r"[\s,;]+").unwrap();
+        assert_eq!(texts(&gaps, ",a, b;;c "), ["a", "b", "c"]);
+        assert!(RegexTokenizer::new("(").is_err());
+    }
+}
@@ -0,0 +1,418 @@
+//! Tokenizing social media and web text.
+
+use super::tokenizer::push_word_bound_segments;
+use super::{TokenKind, TokenRef, Tokenizer};
+use unicode_properties::{GeneralCategory, UnicodeGeneralCategory};
+
+/// A tokenizer for tweets, chat and web text, in the spirit of NLTK's `TweetTokenizer`.
+///
+/// URLs, email addresses, `#hashtags`, `@mentions`, numbers (`3.14`, `-2`, `1,000`,
+/// `$5.00`, `50%`) and emoticons (`:-)`, `;P`, `<3`, `^_^`) are kept as single tokens
+/// and tagged with the matching [`TokenKind`] (emoticons count as
+/// [`TokenKind::Emoji`]). Everything else is split like [`super::PunctuationTokenizer`]:
+/// UAX #29 words, with punctuation, symbols and emoji as tokens of their own.
+///
+/// ```
+/// use textprep::tokenize::{Tokenizer, WebTokenizer};
+///
+/// let tokens = WebTokenizer.tokenize("@ana see https://ex.com/a?b=1 :-) #rust");
+/// let texts: Vec<&str> = tokens.iter().map(|t| t.text).collect();
+/// assert_eq!(texts, ["@ana", "see", "https://ex.com/a?b=1", ":-)", "#rust"]);
+/// ```
+///
+/// Entities are only recognized where a token can start: at the beginning of the text,
+/// or after whitespace or punctuation other than `.`, `,`, `-`, `_`, `'`, `/`, `@`,
+/// `#`, `&`. Trailing sentence punctuation is not part of a URL or email address, and a
+/// closing bracket is only kept when the URL opened one
+/// (`https://en.wikipedia.org/wiki/Rust_(film)`).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WebTokenizer;
+
+impl Tokenizer for WebTokenizer {
+    fn tokenize_into<'a>(&self, text: &'a str, out: &mut Vec<TokenRef<'a>>) {
+        out.clear();
+        let mut gap_start = 0;
+        let mut prev: Option<char> = None;
+        let mut scans = Scans::default();
+        let mut i = 0;
+        while let Some(c) = text[i..].chars().next() {
+            let entity = if can_start(prev) {
+                entity_at(text, i, &mut scans)
+            } else {
+                None
+            };
+            match entity {
+                Some((len, kind)) => {
+                    push_word_bound_segments(&text[gap_start..i], gap_start, out);
+                    out.push(TokenRef::with_kind(&text[i..i + len], i, kind));
+                    i += len;
+                    gap_start = i;
+                    prev = text[..i].chars().next_back();
+                }
+                None => {
+                    i += c.len_utf8();
+                    prev = Some(c);
+                }
+            }
+        }
+        push_word_bound_segments(&text[gap_start..], gap_start, out);
+    }
+}
+
+/// Whether an entity may start right after `prev`.
+fn can_start(prev: Option<char>) -> bool {
+    match prev {
+        None => true,
+        Some(c) => !c.is_alphanumeric() && !".,-_'’/@#&".contains(c),
+    }
+}
+
+/// How far failed scans for an email address or emoticon reached into the text.
+///
+/// Starting further into the same run of local-part characters (`+++…`) or brackets
+/// (`(((…`) sees the same rest and fails the same way, so these positions are skipped;
+/// without this a long run is rescanned once per character.
+#[derive(Debug, Default)]
+struct Scans {
+    email: usize,
+    emoticon: usize,
+}
+
+/// The length and kind of the entity at byte `i` of `text`.
+fn entity_at(text: &str, i: usize, scans: &mut Scans) -> Option<(usize, TokenKind)> {
+    let s = &text[i..];
+    url(s)
+        .map(|n| (n, TokenKind::Url))
+        .or_else(|| {
+            if i < scans.email {
+                return None;
+            }
+            let found = email(s);
+            if found.is_none() && !s.starts_with('.') {
+                scans.email = i + local_part_len(s);
+            }
+            found.map(|n| (n, TokenKind::Email))
+        })
+        .or_else(|| handle(s, '#').map(|n| (n, TokenKind::Hashtag)))
+        .or_else(|| handle(s, '@').map(|n| (n, TokenKind::Mention)))
+        .or_else(|| {
+            if i < scans.emoticon {
+                return None;
+            }
+            let found = emoticon(s);
+            if found.is_none() {
+                scans.emoticon = i + bracket_run_len(s);
+            }
+            found.map(|n| (n, TokenKind::Emoji))
+        })
+        .or_else(|| number(s).map(|n| (n, TokenKind::Number)))
+}
+
+fn url(s: &str) -> Option<usize> {
+    let prefix = ["http://", "https://", "ftp://", "www."]
+        .into_iter()
+        .find(|p| {
+            s.len() > p.len() && s.as_bytes()[..p.len()].eq_ignore_ascii_case(p.as_bytes())
+        })?;
+    if !s[prefix.len()..].starts_with(char::is_alphanumeric) {
+        return None;
+    }
+    let mut url = s
+        .split(|c: char| c.is_whitespace() || "<>\"".contains(c))
+        .next()
+        .unwrap_or(s);
+    // Opened minus closed `()`, `[]` and `{}`.
+    let mut depth = [0isize; 3];
+    for c in url.chars() {
+        match c {
+            '(' => depth[0] += 1,
+            ')' => depth[0] -= 1,
+            '[' => depth[1] += 1,
+            ']' => depth[1] -= 1,
+            '{' => depth[2] += 1,
+            '}' => depth[2] -= 1,
+            _ => {}
+        }
+    }
+    // Drop trailing punctuation, and closing brackets the URL did not open.
+    while let Some(last) = url.chars().next_back() {
+        let unbalanced = match last {
+            ')' | ']' | '}' => {
+                let d = &mut depth[match last {
+                    ')' => 0,
+                    ']' => 1,
+                    _ => 2,
+                }];
+                *d += 1;
+                *d <= 0
+            }
+            _ => ".,;:!?'\"’”".contains(last),
+        };
+        if !unbalanced {
+            break;
+        }
+        url = &url[..url.len() - last.len_utf8()];
+    }
+    Some(url.len())
+}
+
+/// The length of the run of characters allowed in the local part of an email address.
+fn local_part_len(s: &str) -> usize {
+    s.find(|c: char| !(c.is_alphanumeric() || "._%+-".contains(c)))
+        .unwrap_or(s.len())
+}
+
+fn email(s: &str) -> Option<usize> {
+    let local = local_part_len(s);
+    if local == 0 || s.starts_with('.') || !s[local..].starts_with('@') {
+        return None;
+    }
+    let domain = &s[local + 1..];
+    let len = domain
+        .find(|c: char| !(c.is_alphanumeric() || c == '-' || c == '.'))
+        .unwrap_or(domain.len());
+    let domain = domain[..len].trim_end_matches(['.', '-']);
+    let labels_ok = domain
+        .split('.')
+        .all(|l| !l.is_empty() && !l.starts_with('-'));
+    let tld = domain.rsplit('.').next().unwrap_or("");
+    let tld_ok = tld.chars().count() >= 2 && tld.chars().all(char::is_alphabetic);
+    (domain.contains('.') && labels_ok && tld_ok).then_some(local + 1 + domain.len())
+}
+
+/// `#tag` or `@name`: the sigil and word characters, at least one of them a letter.
+fn handle(s: &str, sigil: char) -> Option<usize> {
+    let rest = s.strip_prefix(sigil)?;
+    let len = rest
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(rest.len());
+    rest[..len]
+        .chars()
+        .any(char::is_alphabetic)
+        .then_some(1 + len)
+}
+
+/// Emoticons that do not follow the eyes–nose–mouth pattern.
+const FIXED_EMOTICONS: &[&str] = &[
+    "¯\\_(ツ)_/¯",
+    "</3",
+    "<3",
+    "^_^",
+    "^^",
+    "-_-",
+    "o_O",
+    "O_o",
+    "o.O",
+    "O.o",
+    "T_T",
+    ">_<",
+    ">.<",
+];
+
+/// Western emoticons: eyes, an optional nose and a (repeated) mouth, like `:-)))`,
+/// `;P` or `=D`, or mirrored like `(-:`. `x` eyes need a nose (`x-D`), as `XD` and
+/// `XP` are also words. The emoticon must not run into a word.
+fn emoticon(s: &str) -> Option<usize> {
+    let len = FIXED_EMOTICONS
+        .iter()
+        .find(|e| s.starts_with(*e))
+        .map(|e| e.len())
+        .or_else(|| face(s))
+        .or_else(|| mirrored_face(s))?;
+    let next = s[len..].chars().next();
+    (!next.is_some_and(char::is_alphanumeric)).then_some(len)
+}
+
+fn face(s: &str) -> Option<usize> {
+    let b = s.as_bytes();
+    let eyes = *b.first()?;
+    let mouths: &[u8] = match eyes {
+        b':' | b';' | b'=' => b")]}([{DPpOo/\\|*3$@",
+        b'x' | b'X' => b"DP",
+        _ => return None,
+    };
+    let needs_nose = matches!(eyes, b'x' | b'X');
+    // `:-)`, and `:o` where the `o` is the mouth rather than a nose.
+    let nose = if needs_nose {
+        b.get(1) == Some(&b'-')
+    } else {
+        matches!(b.get(1), Some(b'-' | b'o' | b'\'' | b'^'))
+    };
+    [2, 1]
+        .into_iter()
+        .filter(|&i| if i == 1 { !needs_nose } else { nose })
+        .find_map(|i| {
+            let mouth = *b.get(i).filter(|m| mouths.contains(m))?;
+            Some(i + b[i..].iter().take_while(|&&m| m == mouth).count())
+        })
+}
+
+/// The length of the run of one of `()[]` at the start of `s`.
+fn bracket_run_len(s: &str) -> usize {
+    let b = s.as_bytes();
+    match b.first() {
+        Some(&mouth) if b"()[]".contains(&mouth) => b.iter().take_while(|&&m| m == mouth).count(),
+        _ => 0,
+    }
+}
+
+fn mirrored_face(s: &str) -> Option<usize> {
+    let b = s.as_bytes();
+    let run = bracket_run_len(s);
+    if run == 0 {
+        return None;
+    }
+    let mut i = run;
+    if b.get(i) == Some(&b'-') {
+        i += 1;
+    }
+    matches!(b.get(i), Some(b':' | b';')).then_some(i + 1)
+}
+
+/// Digits with single `.`/`,` separators between them, optionally preceded by a sign
+/// or currency symbol and followed by `%`. A number running into letters (`3rd`) is
+/// left to UAX #29.
+fn number(s: &str) -> Option<usize> {
+    let mut i = 0;
+    let mut chars = s.chars().peekable();
+    if let Some(&c) = chars.peek() {
+        if c == '+'
+            || c == '-'
+            || c == '−'
+            || c.general_category() == GeneralCategory::CurrencySymbol
+        {
+            i += c.len_utf8();
+            chars.next();
+        }
+    }
+    let mut end = None;
+    while let Some(c) = chars.next() {
+        if c.is_ascii_digit() || c.general_category() == GeneralCategory::DecimalNumber {
+            i += c.len_utf8();
+            end = Some(i);
+        } else if (c == '.' || c == ',')
+            && end == Some(i)
+            && chars.peek().is_some_and(|d| d.is_numeric())
+        {
+            i += 1;
+        } else {
+            break;
+        }
+    }
+    let mut end = end?;
+    if s[end..].starts_with('%') {
+        end += 1;
+    }
+    let next = s[end..].chars().next();
+    (!next.is_some_and(char::is_alphanumeric)).then_some(end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(text: &str) -> Vec<(&str, TokenKind)> {
+        WebTokenizer
+            .tokenize(text)
+            .into_iter()
+            .map(|t| (t.text, t.kind))
+            .collect()
+    }
+
+    #[test]
+    fn test_entities_stay_whole() {
+        use TokenKind::*;
+        assert_eq!(
+            tokens("Mail user@mail.com or see https://ex.com/a?b=1. #MachineLearning @handle!"),
+            [
+                ("Mail", Word),
+                ("user@mail.com", Email),
+                ("or", Word),
+                ("see", Word),
+                ("https://ex.com/a?b=1", Url),
+                (".", Punctuation),
+                ("#MachineLearning", Hashtag),
+                ("@handle", Mention),
+                ("!", Punctuation),
+            ]
+        );
+        assert_eq!(
+            tokens("pi is 3.14, costs $5.00 (-2%) :-) ;P <3"),
+            [
+                ("pi", Word),
+                ("is", Word),
+                ("3.14", Number),
+                (",", Punctuation),
+                ("costs", Word),
+                ("$5.00", Number),
+                ("(", Punctuation),
+                ("-2%", Number),
+                (")", Punctuation),
+                (":-)", Emoji),
+                (";P", Emoji),
+                ("<3", Emoji),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lookalikes_are_not_entities() {
+        let texts = |t| tokens(t).into_iter().map(|(s, _)| s).collect::<Vec<_>>();
+        assert_eq!(texts("C# v1.2.3 3rd"), ["C", "#", "v1.2.3", "3rd"]);
+        assert_eq!(
+            texts("ratio 4:3, f(x)"),
+            ["ratio", "4", ":", "3", ",", "f", "(", "x", ")"]
+        );
+        assert_eq!(
+            texts("user@localhost #1"),
+            ["user", "@", "localhost", "#", "1"]
+        );
+        assert_eq!(
+            texts("(https://en.wikipedia.org/wiki/Rust_(film))"),
+            ["(", "https://en.wikipedia.org/wiki/Rust_(film)", ")"]
+        );
+        assert_eq!(
+            tokens("Windows XP x-D"),
+            [
+                ("Windows", TokenKind::Word),
+                ("XP", TokenKind::Word),
+                ("x-D", TokenKind::Emoji),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_long_runs() {
+        // Each run used to be rescanned from every character in it.
+        for c in ["+", "%", "(", ")"] {
+            let text = c.repeat(40_000);
+            let words = super::super::PunctuationTokenizer.tokenize(&text);
+            assert_eq!(WebTokenizer.tokenize(&text), words, "{c:?}");
+        }
+        let url = format!("http://ex.com/{}", ")".repeat(40_000));
+        assert_eq!(tokens(&url)[0], ("http://ex.com/", TokenKind::Url));
+        assert_eq!(
+            tokens("+a +b@ex.com ((x (-:"),
+            [
+                ("+", TokenKind::Symbol),
+                ("a", TokenKind::Word),
+                ("+b@ex.com", TokenKind::Email),
+                ("((", TokenKind::Punctuation),
+                ("x", TokenKind::Word),
+                ("(-:", TokenKind::Emoji),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_offsets() {
+        let text = "Ça va 😀 @zoë: www.ex.com";
+        let tokens = WebTokenizer.tokenize_with_offsets(text);
+        let chars: Vec<char> = text.chars().collect();
+        for t in &tokens {
+            assert_eq!(chars[t.start..t.end].iter().collect::<String>(), t.text);
+        }
+        let texts: Vec<&str> = tokens.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(texts, ["Ça", "va", "😀", "@zoë", ":", "www.ex.com"]);
+    }
+}
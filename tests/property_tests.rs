@@ -152,6 +152,139 @@ proptest! {
         }
     }
 
+    #[test]
+    fn byte_offsets_slice_the_original_text(s in any_reasonable_string()) {
+        use textprep::OffsetUnit;
+
+        let by_byte = textprep::tokenize::tokenize_with_offsets_in(&s, OffsetUnit::Byte);
+        let by_char = textprep::tokenize::tokenize_with_offsets_in(&s, OffsetUnit::Char);
+        prop_assert_eq!(by_byte.len(), by_char.len());
+        let idx = textprep::OffsetIndex::new(&s);
+        for (b, c) in by_byte.iter().zip(&by_char) {
+            prop_assert_eq!(&s[b.start..b.end], b.text.as_str());
+            prop_assert_eq!(idx.byte_to_char(b.start), Some(c.start));
+            prop_assert_eq!(idx.byte_to_char(b.end), Some(c.end));
+        }
+
+        let mut ft = textprep::FlashText::new().with_offset_unit(OffsetUnit::Byte);
+        ft.add_keyword("Müller", "muller");
+        ft.add_keyword("hello", "hello");
+        for m in ft.find(&s) {
+            prop_assert_eq!(s[m.start..m.end].to_ascii_lowercase(), m.keyword.to_ascii_lowercase());
+        }
+    }
+
+    #[test]
+    fn tokenizers_yield_ordered_non_empty_slices(s in any_reasonable_string()) {
+        use textprep::tokenize::{
+            GraphemeTokenizer, ParagraphTokenizer, PunctuationTokenizer, SentenceSplitter,
+            TokenKind, Tokenizer, WhitespaceTokenizer, WordTokenizer,
+        };
+
+        let sentences = SentenceSplitter::default();
+        let tokenizers: [&dyn Tokenizer; 9] = [
+            &WhitespaceTokenizer,
+            &WordTokenizer,
+            &PunctuationTokenizer,
+            &GraphemeTokenizer,
+            &sentences,
+            &ParagraphTokenizer,
+            textprep::tokenize::cjk::bundled(),
+            textprep::tokenize::sea::bundled(),
+            &textprep::tokenize::identifier::IdentifierTokenizer,
+        ];
+        for tokenizer in tokenizers {
+            let tokens = tokenizer.tokenize(&s);
+            for t in &tokens {
+                prop_assert!(!t.text.is_empty());
+                prop_assert_eq!(&s[t.byte_start..t.byte_end], t.text);
+                prop_assert_eq!(t.kind, TokenKind::of(t.text));
+                prop_assert_eq!(t.char_start, Some(s[..t.byte_start].chars().count()));
+                prop_assert_eq!(t.char_end, Some(s[..t.byte_end].chars().count()));
+            }
+            for w in tokens.windows(2) {
+                prop_assert!(w[0].byte_end <= w[1].byte_start);
+            }
+        }
+
+        let ws: Vec<&str> = WhitespaceTokenizer.tokenize(&s).iter().map(|t| t.text).collect();
+        prop_assert_eq!(ws, s.split_whitespace().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn web_tokenizer_yields_ordered_slices(
+        s in proptest::collection::vec(
+            proptest::sample::select(vec![
+                "https://ex.com/a_(b)", "www.x.org", "a.b@ex.com", "#tag", "@ana", ":-)", "(:",
+                "<3", "3.14", "-2", "$5,000", "%", " ", "\n", ".", ",", "(", ")", "@", "#", ":",
+                "é", "ß", "😀", "👍🏽", "a", "Z", "1", "-", "_",
+            ]),
+            0..40,
+        ).prop_map(|parts| parts.concat())
+    ) {
+        use textprep::tokenize::{Tokenizer, WebTokenizer};
+
+        let tokens = WebTokenizer.tokenize(&s);
+        for t in &tokens {
+            prop_assert!(!t.text.is_empty());
+            prop_assert!(!t.text.chars().all(char::is_whitespace));
+            prop_assert_eq!(&s[t.byte_start..t.byte_end], t.text);
+            prop_assert_eq!(t.char_start, Some(s[..t.byte_start].chars().count()));
+        }
+        for w in tokens.windows(2) {
+            prop_assert!(w[0].byte_end <= w[1].byte_start);
+        }
+    }
+
+    #[test]
+    fn token_iter_matches_tokenize_with_offsets(s in any_reasonable_string()) {
+        use textprep::tokenize::{tokenize_iter, Token, Tokenizer, WordTokenizer};
+        use textprep::OffsetUnit;
+
+        let lazy: Vec<_> = tokenize_iter(&s).collect();
+        prop_assert_eq!(&lazy, &WordTokenizer.tokenize(&s));
+        let owned: Vec<Token> = lazy.iter().map(|t| t.to_token(&s)).collect();
+        prop_assert_eq!(owned, WordTokenizer.tokenize_with_offsets_in(&s, OffsetUnit::Char));
+    }
+
+    #[test]
+    fn graphemes_with_offsets_tile_the_text(s in any_reasonable_string()) {
+        let tokens = textprep::tokenize::graphemes_with_offsets(&s);
+        let idx = textprep::OffsetIndex::new(&s);
+        prop_assert_eq!(tokens.len(), idx.len_graphemes());
+        let mut next = 0usize;
+        for (g, t) in tokens.iter().enumerate() {
+            prop_assert_eq!(t.start, next);
+            prop_assert_eq!(idx.grapheme_to_char(g), Some(t.start));
+            prop_assert_eq!(slice_by_char_range(&s, t.start, t.end), t.text.as_str());
+            next = t.end;
+        }
+        prop_assert_eq!(next, s.chars().count());
+        let joined: String = textprep::ngram::grapheme_ngrams(&s, 1).concat();
+        prop_assert_eq!(joined, s);
+    }
+
+    #[test]
+    fn offset_index_agrees_with_naive_counting(s in any_reasonable_string()) {
+        let idx = textprep::OffsetIndex::new(&s);
+        prop_assert_eq!(idx.len_bytes(), s.len());
+        prop_assert_eq!(idx.len_chars(), s.chars().count());
+        prop_assert_eq!(idx.len_utf16(), s.encode_utf16().count());
+
+        for (ci, (b, _)) in s.char_indices().enumerate() {
+            prop_assert_eq!(idx.char_to_byte(ci), Some(b));
+            prop_assert_eq!(idx.byte_to_char(b), Some(ci));
+            prop_assert_eq!(idx.byte_to_utf16(b), Some(s[..b].encode_utf16().count()));
+        }
+
+        for g in 0..idx.len_graphemes() {
+            let span = idx.span_from_graphemes(g, g + 1).unwrap();
+            prop_assert!(s.is_char_boundary(span.byte_start));
+            prop_assert!(s.is_char_boundary(span.byte_end));
+            prop_assert_eq!(span.char_end - span.char_start, s[span.byte_range()].chars().count());
+        }
+    }
+
     #[test]
     fn similarity_metrics_are_symmetric_and_bounded(
         a in any_reasonable_string(),
@@ -222,6 +355,26 @@ proptest! {
         assert_flash_matches_sane(&s, &out)?;
     }
 
+    #[test]
+    fn flashtext_find_tokens_matches_whole_tokens(s in any_reasonable_string()) {
+        use textprep::tokenize::{Tokenizer, WordTokenizer};
+
+        let mut ft = textprep::FlashText::new().with_offset_unit(textprep::OffsetUnit::Byte);
+        ft.add_keyword("hello", "hello");
+        ft.add_keyword("hello world", "hello world");
+        ft.add_keyword("Müller", "muller");
+
+        let tokens = WordTokenizer.tokenize(&s);
+        let mut last_end = 0usize;
+        for m in ft.find_tokens(&s, &WordTokenizer) {
+            prop_assert!(last_end <= m.start);
+            prop_assert!(tokens.iter().any(|t| t.byte_start == m.start));
+            prop_assert!(tokens.iter().any(|t| t.byte_end == m.end));
+            prop_assert_eq!(s[m.start..m.end].to_ascii_lowercase(), m.keyword.to_ascii_lowercase());
+            last_end = m.end;
+        }
+    }
+
     #[test]
     fn flashtext_finds_embedded_keyword(
         prefix in any_reasonable_string(),
@@ -266,4 +419,43 @@ proptest! {
         let out2 = textprep::scrub_with(&out1, &cfg);
         prop_assert_eq!(out1, out2);
     }
+
+    #[test]
+    fn tokenize_scrubbed_spans_slice_the_original_text(s in any_reasonable_string()) {
+        use textprep::tokenize::tokenize_scrubbed_in;
+        use textprep::OffsetUnit;
+
+        let cfg = textprep::ScrubConfig::search_key_strict_invisibles();
+        let tokens = tokenize_scrubbed_in(&s, &cfg, OffsetUnit::Byte);
+        let mut prev = (0, 0);
+        for t in &tokens {
+            prop_assert!(!t.text.is_empty());
+            prop_assert!(t.start < t.end && t.end <= s.len());
+            prop_assert!(s.is_char_boundary(t.start) && s.is_char_boundary(t.end));
+            // Parts of one split word share a span; otherwise spans move forward.
+            prop_assert!((t.start, t.end) == prev || t.start >= prev.1);
+            prev = (t.start, t.end);
+        }
+    }
+
+    #[test]
+    fn document_tokens_match_their_slices(s in any_reasonable_string()) {
+        use textprep::tokenize::{lines_with_offsets_in, Document};
+        use textprep::OffsetUnit;
+
+        let doc = Document::new(&s);
+        let mut prev_end = 0;
+        for t in doc.tokens() {
+            prop_assert_eq!(&s[t.byte_start..t.byte_end], t.text);
+            prop_assert_eq!(t.char_start, Some(s[..t.byte_start].chars().count()));
+            prop_assert!(t.byte_start >= prev_end);
+            prev_end = t.byte_end;
+        }
+
+        let lines = lines_with_offsets_in(&s, OffsetUnit::Byte);
+        for t in &lines {
+            prop_assert_eq!(&s[t.start..t.end], t.text.as_str());
+        }
+        prop_assert!(lines.len() >= s.lines().count());
+    }
 }